@@ -58,6 +58,10 @@ pub const CMD_GEN_STUB_LIB: &str = "gen-stub-lib";
 pub const CMD_PREPROCESS_HOST: &str = "preprocess-host";
 
 pub const FLAG_EMIT_LLVM_IR: &str = "emit-llvm-ir";
+pub const FLAG_EMIT_LLVM_BC: &str = "emit-llvm-bc";
+pub const FLAG_PROBE_STACK: &str = "probe-stack";
+pub const FLAG_STACK_LIMIT_KB: &str = "stack-limit-kb";
+pub const FLAG_KEEP_FRAME_POINTERS: &str = "keep-frame-pointers";
 pub const FLAG_PROFILING: &str = "profiling";
 pub const FLAG_BUNDLE: &str = "bundle";
 pub const FLAG_DEV: &str = "dev";
@@ -122,6 +126,30 @@ pub fn build_app() -> Command {
         .action(ArgAction::SetTrue)
         .required(false);
 
+    let flag_emit_llvm_bc = Arg::new(FLAG_EMIT_LLVM_BC)
+        .long(FLAG_EMIT_LLVM_BC)
+        .help("Emit a `.bc` file containing the LLVM bitcode of the program")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
+    let flag_probe_stack = Arg::new(FLAG_PROBE_STACK)
+        .long(FLAG_PROBE_STACK)
+        .help("Probe large stack frames, so a stack overflow always faults (x86 only)")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
+    let flag_stack_limit_kb = Arg::new(FLAG_STACK_LIMIT_KB)
+        .long(FLAG_STACK_LIMIT_KB)
+        .help("Crash with \"stack overflow\" once Roc code uses this many kilobytes of stack")
+        .value_parser(value_parser!(u32))
+        .required(false);
+
+    let flag_keep_frame_pointers = Arg::new(FLAG_KEEP_FRAME_POINTERS)
+        .long(FLAG_KEEP_FRAME_POINTERS)
+        .help("Keep frame pointers, so profilers can walk the stack through Roc code")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
     let flag_profiling = Arg::new(FLAG_PROFILING)
         .long(FLAG_PROFILING)
         .help("Keep debug info in the final generated program even in optimized builds")
@@ -196,6 +224,10 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_llvm_bc.clone())
+            .arg(flag_probe_stack.clone())
+            .arg(flag_stack_limit_kb.clone())
+            .arg(flag_keep_frame_pointers.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
@@ -248,6 +280,10 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_llvm_bc.clone())
+            .arg(flag_probe_stack.clone())
+            .arg(flag_stack_limit_kb.clone())
+            .arg(flag_keep_frame_pointers.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
@@ -279,6 +315,10 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_llvm_bc.clone())
+            .arg(flag_probe_stack.clone())
+            .arg(flag_stack_limit_kb.clone())
+            .arg(flag_keep_frame_pointers.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
@@ -294,6 +334,10 @@ pub fn build_app() -> Command {
             .arg(flag_opt_size.clone())
             .arg(flag_dev.clone())
             .arg(flag_emit_llvm_ir.clone())
+            .arg(flag_emit_llvm_bc.clone())
+            .arg(flag_probe_stack.clone())
+            .arg(flag_stack_limit_kb.clone())
+            .arg(flag_keep_frame_pointers.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
             .arg(flag_linker.clone())
@@ -438,6 +482,10 @@ pub fn build_app() -> Command {
         .arg(flag_opt_size)
         .arg(flag_dev)
         .arg(flag_emit_llvm_ir)
+        .arg(flag_emit_llvm_bc)
+        .arg(flag_probe_stack)
+        .arg(flag_stack_limit_kb)
+        .arg(flag_keep_frame_pointers)
         .arg(flag_profiling)
         .arg(flag_time)
         .arg(flag_linker)
@@ -832,6 +880,30 @@ pub fn build(
         user_error!("Cannot emit llvm ir while using a dev backend.");
     }
 
+    let emit_llvm_bc = matches.get_flag(FLAG_EMIT_LLVM_BC);
+    if emit_llvm_bc && !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+        user_error!("Cannot emit llvm bitcode while using a dev backend.");
+    }
+
+    let probe_stack = matches.get_flag(FLAG_PROBE_STACK);
+    if probe_stack && !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+        user_error!("Cannot emit stack probes while using a dev backend.");
+    }
+
+    let stack_limit: Option<u32> = matches
+        .try_get_one::<u32>(FLAG_STACK_LIMIT_KB)
+        .ok()
+        .flatten()
+        .map(|x| x * 1024);
+    if stack_limit.is_some() && !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+        user_error!("Cannot check a stack limit while using a dev backend.");
+    }
+
+    let keep_frame_pointers = matches.get_flag(FLAG_KEEP_FRAME_POINTERS);
+    if keep_frame_pointers && !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+        user_error!("Cannot keep frame pointers while using a dev backend.");
+    }
+
     let emit_debug_info = matches.get_flag(FLAG_PROFILING)
         || matches!(opt_level, OptLevel::Development | OptLevel::Normal);
     let emit_timings = matches.get_flag(FLAG_TIME);
@@ -888,6 +960,10 @@ pub fn build(
         opt_level,
         emit_debug_info,
         emit_llvm_ir,
+        emit_llvm_bc,
+        probe_stack,
+        stack_limit,
+        keep_frame_pointers,
         fuzz,
     };
 
@@ -1574,11 +1574,6 @@ fn expr_spec<'a>(
 
             erasure_load(builder, block, value, *field, loaded_type)
         }
-        RuntimeErrorFunction(_) => {
-            let type_id = layout_spec(env, builder, interner, interner.get_repr(layout))?;
-
-            builder.add_terminate(block, type_id)
-        }
         GetTagId { .. } => {
             // TODO touch heap cell in recursive cases
 
@@ -1850,5 +1845,7 @@ fn erasure_load(
             // Cast the unknown cell to the wanted type
             builder.add_unknown_with(block, &[unknown_heap_cell_value], loaded_type)
         }
+        // the refcounter is not part of the modeled erasure
+        ErasedField::Refcounter => builder.add_unknown_with(block, &[], loaded_type),
     }
 }
@@ -3,7 +3,7 @@ use crate::link::{
 };
 use bumpalo::Bump;
 use inkwell::memory_buffer::MemoryBuffer;
-use roc_error_macros::internal_error;
+use roc_error_macros::{internal_error, user_error};
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::{module_from_builtins, LlvmBackendMode};
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
@@ -84,6 +84,10 @@ pub struct CodeGenOptions {
     pub opt_level: OptLevel,
     pub emit_debug_info: bool,
     pub emit_llvm_ir: bool,
+    pub emit_llvm_bc: bool,
+    pub probe_stack: bool,
+    pub stack_limit: Option<u32>,
+    pub keep_frame_pointers: bool,
     pub fuzz: bool,
 }
 
@@ -102,6 +106,10 @@ pub fn gen_from_mono_module<'a>(
     let path = roc_file_path;
     let debug = code_gen_options.emit_debug_info;
     let emit_llvm_ir = code_gen_options.emit_llvm_ir;
+    let emit_llvm_bc = code_gen_options.emit_llvm_bc;
+    let probe_stack = code_gen_options.probe_stack;
+    let stack_limit = code_gen_options.stack_limit;
+    let keep_frame_pointers = code_gen_options.keep_frame_pointers;
     let fuzz = code_gen_options.fuzz;
     let opt = code_gen_options.opt_level;
 
@@ -131,6 +139,10 @@ pub fn gen_from_mono_module<'a>(
             backend_mode,
             debug,
             emit_llvm_ir,
+            emit_llvm_bc,
+            probe_stack,
+            stack_limit,
+            keep_frame_pointers,
             fuzz,
         ),
     }
@@ -149,6 +161,10 @@ fn gen_from_mono_module_llvm<'a>(
     backend_mode: LlvmBackendMode,
     emit_debug_info: bool,
     emit_llvm_ir: bool,
+    emit_llvm_bc: bool,
+    probe_stack: bool,
+    stack_limit: Option<u32>,
+    keep_frame_pointers: bool,
     fuzz: bool,
 ) -> GenFromMono<'a> {
     use crate::target::{self, convert_opt_level};
@@ -163,6 +179,11 @@ fn gen_from_mono_module_llvm<'a>(
     let context = Context::create();
     let module = arena.alloc(module_from_builtins(target, &context, "app"));
 
+    // wasm modules are emitted as bitcode, and keep the layout of the wasm builtins
+    if target.architecture() != Architecture::Wasm32 {
+        target::set_module_target(module, target);
+    }
+
     let app_ll_file = {
         let mut roc_file_path_buf = PathBuf::from(roc_file_path);
         roc_file_path_buf.set_extension("ll");
@@ -216,6 +237,8 @@ fn gen_from_mono_module_llvm<'a>(
             .keys()
             .copied()
             .collect(),
+        probe_stack,
+        stack_limit,
     };
 
     // does not add any externs for this mode (we have a host) but cleans up some functions around
@@ -253,6 +276,10 @@ fn gen_from_mono_module_llvm<'a>(
 
     env.dibuilder.finalize();
 
+    if keep_frame_pointers {
+        roc_gen_llvm::llvm::build::keep_frame_pointers(module);
+    }
+
     if !emit_debug_info {
         module.strip_debug_info();
     }
@@ -274,6 +301,15 @@ fn gen_from_mono_module_llvm<'a>(
         );
     }
 
+    if emit_llvm_bc {
+        let app_bc_file = app_ll_file.with_extension("bc");
+
+        eprintln!("Emitting LLVM bitcode to {}", &app_bc_file.display());
+        if !module.write_bitcode_to_path(&app_bc_file) {
+            user_error!("Could not write LLVM bitcode to {}", &app_bc_file.display());
+        }
+    }
+
     // Uncomment this to see the module's optimized LLVM instruction output:
     // env.module.print_to_stderr();
 
@@ -1264,6 +1300,10 @@ pub fn build_str_test<'a>(
         opt_level: OptLevel::Normal,
         emit_debug_info: false,
         emit_llvm_ir: false,
+        emit_llvm_bc: false,
+        probe_stack: false,
+        stack_limit: None,
+        keep_frame_pointers: false,
         fuzz: false,
     };
 
@@ -1,4 +1,5 @@
 use inkwell::{
+    module::Module,
     targets::{
         CodeModel, InitializationConfig, RelocMode, Target as LlvmTarget, TargetMachine,
         TargetTriple,
@@ -118,6 +119,16 @@ pub fn target_machine(
     )
 }
 
+/// Sets the module's triple and data layout to those of `target`, so that struct layouts and
+/// pointer sizes match the target rather than the machine the compiler runs on.
+pub fn set_module_target(module: &Module, target: Target) {
+    let target_machine = target_machine(target, OptimizationLevel::None, RelocMode::PIC)
+        .unwrap_or_else(|| internal_error!("no target machine for {:?}", target));
+
+    module.set_triple(&target_machine.get_triple());
+    module.set_data_layout(&target_machine.get_target_data().get_data_layout());
+}
+
 pub fn convert_opt_level(level: OptLevel) -> OptimizationLevel {
     match level {
         OptLevel::Development | OptLevel::Normal => OptimizationLevel::None,
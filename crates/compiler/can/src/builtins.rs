@@ -8,7 +8,7 @@ use roc_module::ident::TagName;
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::Symbol;
 use roc_region::all::{Loc, Region};
-use roc_types::subs::{VarStore, Variable};
+use roc_types::subs::{ExhaustiveMark, VarStore, Variable};
 
 /// We use a rust macro to ensure that every LowLevel gets handled
 macro_rules! map_symbol_to_lowlevel_and_arity {
@@ -420,6 +420,8 @@ fn defn(
         expr_var: var_store.fresh(),
         pattern_vars: SendMap::default(),
         annotation: None,
+        pattern_var: var_store.fresh(),
+        exhaustive: ExhaustiveMark::new(var_store),
     }
 }
 
@@ -548,6 +550,8 @@ fn to_num_checked(symbol: Symbol, var_store: &mut VarStore, lowlevel: LowLevel)
         expr_var: record_var,
         pattern_vars: SendMap::default(),
         annotation: None,
+        pattern_var: var_store.fresh(),
+        exhaustive: ExhaustiveMark::new(var_store),
     };
 
     let body = LetNonRec(Box::new(def), Box::new(no_region(cont)));
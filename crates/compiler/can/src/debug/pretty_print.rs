@@ -105,6 +105,8 @@ fn def<'a>(c: &Ctx, f: &'a Arena<'a>, d: &'a Def) -> DocBuilder<'a, Arena<'a>> {
         expr_var: _,
         pattern_vars: _,
         annotation: _,
+        pattern_var: _,
+        exhaustive: _,
     } = d;
 
     def_help(c, f, &loc_pattern.value, &loc_expr.value)
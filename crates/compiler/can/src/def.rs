@@ -43,7 +43,7 @@ use roc_parse::pattern::PatternType;
 use roc_problem::can::ShadowKind;
 use roc_problem::can::{CycleEntry, Problem, RuntimeError};
 use roc_region::all::{Loc, Region};
-use roc_types::subs::IllegalCycleMark;
+use roc_types::subs::{ExhaustiveMark, IllegalCycleMark};
 use roc_types::subs::{VarStore, Variable};
 use roc_types::types::AliasCommon;
 use roc_types::types::AliasKind;
@@ -66,6 +66,11 @@ pub struct Def {
     pub expr_var: Variable,
     pub pattern_vars: SendMap<Symbol, Variable>,
     pub annotation: Option<Annotation>,
+    /// The type of a destructuring pattern, checked for exhaustiveness against `expr_var`.
+    pub pattern_var: Variable,
+    /// Whether the pattern covers every value of the expression's type.
+    /// Only destructuring patterns are checked.
+    pub exhaustive: ExhaustiveMark,
 }
 
 impl Def {
@@ -1814,6 +1819,8 @@ pub(crate) fn sort_can_defs_new(
                                 def.expr_var,
                                 def.annotation,
                                 def.pattern_vars.into_iter().collect(),
+                                def.pattern_var,
+                                def.exhaustive,
                             );
                         }
                     }
@@ -2146,6 +2153,7 @@ fn single_can_def(
     expr_var: Variable,
     opt_loc_annotation: Option<Loc<crate::annotation::Annotation>>,
     pattern_vars: SendMap<Symbol, Variable>,
+    var_store: &mut VarStore,
 ) -> Def {
     let def_annotation = opt_loc_annotation.map(|loc_annotation| Annotation {
         signature: loc_annotation.value.typ,
@@ -2163,6 +2171,8 @@ fn single_can_def(
         },
         pattern_vars,
         annotation: def_annotation,
+        pattern_var: var_store.fresh(),
+        exhaustive: ExhaustiveMark::new(var_store),
     }
 }
 
@@ -2301,6 +2311,7 @@ fn canonicalize_pending_value_def<'a>(
                 expr_var,
                 Some(Loc::at(loc_ann.region, type_annotation)),
                 vars_by_symbol.clone(),
+                var_store,
             );
 
             DefOutput {
@@ -2411,6 +2422,7 @@ fn canonicalize_pending_value_def<'a>(
                 var_store.fresh(),
                 opt_loc_can_ann,
                 SendMap::default(),
+                var_store,
             );
 
             DefOutput {
@@ -2560,6 +2572,7 @@ fn canonicalize_pending_body<'a>(
         expr_var,
         opt_loc_annotation,
         vars_by_symbol,
+        var_store,
     );
 
     DefOutput {
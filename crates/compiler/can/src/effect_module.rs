@@ -215,6 +215,8 @@ fn build_effect_always(
         expr_var: function_var,
         pattern_vars,
         annotation: Some(def_annotation),
+        pattern_var: var_store.fresh(),
+        exhaustive: ExhaustiveMark::new(var_store),
     };
 
     (always_symbol, def)
@@ -409,6 +411,8 @@ fn build_effect_map(
         expr_var: function_var,
         pattern_vars,
         annotation: Some(def_annotation),
+        pattern_var: var_store.fresh(),
+        exhaustive: ExhaustiveMark::new(var_store),
     };
 
     (map_symbol, def)
@@ -628,6 +632,8 @@ fn build_effect_after(
         expr_var: function_var,
         pattern_vars,
         annotation: Some(def_annotation),
+        pattern_var: var_store.fresh(),
+        exhaustive: ExhaustiveMark::new(var_store),
     };
 
     (after_symbol, def)
@@ -711,6 +717,8 @@ fn force_effect(
         expr_var: var_store.fresh(),
         pattern_vars,
         annotation: None,
+        pattern_var: var_store.fresh(),
+        exhaustive: ExhaustiveMark::new(var_store),
     };
 
     let ret_var = var_store.fresh();
@@ -861,6 +869,8 @@ fn build_effect_forever(
         expr_var: function_var,
         pattern_vars,
         annotation: Some(def_annotation),
+        pattern_var: var_store.fresh(),
+        exhaustive: ExhaustiveMark::new(var_store),
     };
 
     (forever_symbol, def)
@@ -929,6 +939,8 @@ fn build_effect_forever_inner_body(
             expr_var: var_store.fresh(),
             pattern_vars,
             annotation: None,
+            pattern_var: var_store.fresh(),
+            exhaustive: ExhaustiveMark::new(var_store),
         }
     };
 
@@ -955,6 +967,8 @@ fn build_effect_forever_inner_body(
         expr_var: var_store.fresh(),
         pattern_vars: Default::default(),
         annotation: None,
+        pattern_var: var_store.fresh(),
+        exhaustive: ExhaustiveMark::new(var_store),
     };
 
     // recursive call `forever effect`
@@ -1121,6 +1135,8 @@ fn build_effect_loop(
         expr_var: function_var,
         pattern_vars,
         annotation: Some(def_annotation),
+        pattern_var: var_store.fresh(),
+        exhaustive: ExhaustiveMark::new(var_store),
     };
 
     (loop_symbol, def)
@@ -1235,6 +1251,8 @@ fn build_effect_loop_inner_body(
             expr_var: var_store.fresh(),
             pattern_vars,
             annotation: None,
+            pattern_var: var_store.fresh(),
+            exhaustive: ExhaustiveMark::new(var_store),
         }
     };
 
@@ -1497,6 +1515,8 @@ pub fn build_host_exposed_def(
         expr_var,
         pattern_vars,
         annotation: Some(def_annotation),
+        pattern_var: var_store.fresh(),
+        exhaustive: ExhaustiveMark::new(var_store),
     }
 }
 
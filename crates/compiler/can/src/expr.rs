@@ -2145,6 +2145,8 @@ pub fn inline_calls(var_store: &mut VarStore, expr: Expr) -> Expr {
                     expr_var: def.expr_var,
                     pattern_vars: def.pattern_vars,
                     annotation: def.annotation,
+                    pattern_var: def.pattern_var,
+                    exhaustive: def.exhaustive,
                 });
             }
 
@@ -2166,6 +2168,8 @@ pub fn inline_calls(var_store: &mut VarStore, expr: Expr) -> Expr {
                 expr_var: def.expr_var,
                 pattern_vars: def.pattern_vars,
                 annotation: def.annotation,
+                pattern_var: def.pattern_var,
+                exhaustive: def.exhaustive,
             };
 
             let loc_expr = Loc {
@@ -2327,10 +2331,8 @@ pub fn inline_calls(var_store: &mut VarStore, expr: Expr) -> Expr {
                             // Wrap the body in one LetNonRec for each argument,
                             // such that at the end we have all the arguments in
                             // scope with the values the caller provided.
-                            for (
-                                (_param_var, _exhaustive_mark, loc_pattern),
-                                (expr_var, loc_expr),
-                            ) in params.iter().cloned().zip(args.into_iter()).rev()
+                            for ((param_var, annotated_mark, loc_pattern), (expr_var, loc_expr)) in
+                                params.iter().cloned().zip(args.into_iter()).rev()
                             {
                                 // TODO get the correct vars into here.
                                 // Not sure if param_var should be involved.
@@ -2342,6 +2344,8 @@ pub fn inline_calls(var_store: &mut VarStore, expr: Expr) -> Expr {
                                     expr_var,
                                     pattern_vars,
                                     annotation: None,
+                                    pattern_var: param_var,
+                                    exhaustive: annotated_mark.exhaustive,
                                 };
 
                                 loc_answer = Loc {
@@ -2916,12 +2920,16 @@ impl Declarations {
         expr_var: Variable,
         annotation: Option<Annotation>,
         pattern_vars: VecMap<Symbol, Variable>,
+        pattern_var: Variable,
+        exhaustive: ExhaustiveMark,
     ) -> usize {
         let index = self.declarations.len();
 
         let destruct_def = DestructureDef {
             loc_pattern,
             pattern_vars,
+            pattern_var,
+            exhaustive,
         };
 
         let destructure_def_index = Index::push_new(&mut self.destructs, destruct_def);
@@ -3124,6 +3132,8 @@ pub struct FunctionDef {
 pub struct DestructureDef {
     pub loc_pattern: Loc<Pattern>,
     pub pattern_vars: VecMap<Symbol, Variable>,
+    pub pattern_var: Variable,
+    pub exhaustive: ExhaustiveMark,
 }
 
 pub(crate) fn get_lookup_symbols(expr: &Expr) -> Vec<ExpectLookup> {
@@ -27,7 +27,7 @@ use roc_collections::VecMap;
 use roc_module::ident::Lowercase;
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_region::all::{Loc, Region};
-use roc_types::subs::{IllegalCycleMark, Variable};
+use roc_types::subs::{ExhaustiveMark, IllegalCycleMark, Variable};
 use roc_types::types::Type::{self, *};
 use roc_types::types::{
     AliasKind, AnnotationSource, Category, IndexOrField, OptAbleType, PReason, Reason, RecordField,
@@ -2084,16 +2084,20 @@ fn constrain_destructure_def(
 ) -> Constraint {
     let loc_expr = &declarations.expressions[index];
     let expr_var = declarations.variables[index];
-    let expr_var_index = constraints.push_variable(expr_var);
     let opt_annotation = &declarations.annotations[index];
 
     let destructure_def = &declarations.destructs[destructure_def_index.index()];
     let loc_pattern = &destructure_def.loc_pattern;
+    let pattern_var = def_pattern_var(loc_pattern, expr_var, destructure_def.pattern_var);
+    let pattern_var_index = constraints.push_variable(pattern_var);
 
     let mut def_pattern_state =
-        constrain_def_pattern(types, constraints, env, loc_pattern, expr_var_index);
+        constrain_def_pattern(types, constraints, env, loc_pattern, pattern_var_index);
 
     def_pattern_state.vars.push(expr_var);
+    if pattern_var != expr_var {
+        def_pattern_state.vars.push(pattern_var);
+    }
 
     match opt_annotation {
         Some(annotation) => {
@@ -2151,6 +2155,15 @@ fn constrain_destructure_def(
                 constraints.store(signature_index, expr_var, std::file!(), std::line!()),
             ];
             let expr_con = constraints.and_constraint(cons);
+            let expr_con = constrain_destructure_exhaustive(
+                constraints,
+                loc_pattern,
+                loc_expr,
+                expr_var,
+                pattern_var,
+                destructure_def.exhaustive,
+                expr_con,
+            );
 
             constrain_function_def_make_constraint(
                 constraints,
@@ -2173,6 +2186,15 @@ fn constrain_destructure_def(
                 &loc_expr.value,
                 expected_type,
             );
+            let expr_con = constrain_destructure_exhaustive(
+                constraints,
+                loc_pattern,
+                loc_expr,
+                expr_var,
+                pattern_var,
+                destructure_def.exhaustive,
+                expr_con,
+            );
 
             constrain_function_def_make_constraint(
                 constraints,
@@ -2186,6 +2208,53 @@ fn constrain_destructure_def(
     }
 }
 
+/// The variable a def's pattern is constrained against. A destructure that may not be
+/// exhaustive gets its own variable, which only the exhaustiveness check unifies with the
+/// type of the def, the same way a `when` keeps its condition and branch types apart.
+fn def_pattern_var(
+    loc_pattern: &Loc<Pattern>,
+    expr_var: Variable,
+    pattern_var: Variable,
+) -> Variable {
+    if loc_pattern.value.surely_exhaustive() {
+        expr_var
+    } else {
+        pattern_var
+    }
+}
+
+/// Exhaustiveness-check a destructuring pattern like `Ok x = f y` against the type of the
+/// value it destructures, so that missing alternatives are reported instead of being read as
+/// the one the pattern names.
+fn constrain_destructure_exhaustive(
+    constraints: &mut Constraints,
+    loc_pattern: &Loc<Pattern>,
+    loc_expr: &Loc<Expr>,
+    expr_var: Variable,
+    pattern_var: Variable,
+    exhaustive: ExhaustiveMark,
+    expr_con: Constraint,
+) -> Constraint {
+    if loc_pattern.value.surely_exhaustive() {
+        // OPT: identifiers, records and the like cover every value; no check needed.
+        return expr_con;
+    }
+
+    let sketched_rows = sketch_pattern_to_rows(loc_pattern.region, &loc_pattern.value);
+    let pattern_type_index = constraints.push_variable(pattern_var);
+    let expected = constraints.push_expected_type(NoExpectation(pattern_type_index));
+    let exhaustive_constraint = constraints.exhaustive(
+        expr_var,
+        loc_expr.region,
+        Ok((loc_expr.value.category(), expected)),
+        sketched_rows,
+        ExhaustiveContext::BadDestruct,
+        exhaustive,
+    );
+
+    constraints.and_constraint([expr_con, exhaustive_constraint])
+}
+
 fn constrain_value_def(
     types: &mut Types,
     constraints: &mut Constraints,
@@ -2711,11 +2780,16 @@ fn constrain_typed_def(
 ) -> Constraint {
     let expr_var = def.expr_var;
     let expr_type_index = constraints.push_variable(expr_var);
+    let pattern_var = def_pattern_var(&def.loc_pattern, expr_var, def.pattern_var);
+    let pattern_var_index = constraints.push_variable(pattern_var);
 
     let mut def_pattern_state =
-        constrain_def_pattern(types, constraints, env, &def.loc_pattern, expr_type_index);
+        constrain_def_pattern(types, constraints, env, &def.loc_pattern, pattern_var_index);
 
     def_pattern_state.vars.push(expr_var);
+    if pattern_var != expr_var {
+        def_pattern_state.vars.push(pattern_var);
+    }
 
     let arity = annotation.signature.arity();
     let rigids = &env.rigids;
@@ -2915,6 +2989,15 @@ fn constrain_typed_def(
                 annotation_expected,
             );
             let expr_con = attach_resolution_constraints(constraints, env, ret_constraint);
+            let expr_con = constrain_destructure_exhaustive(
+                constraints,
+                &def.loc_pattern,
+                &def.loc_expr,
+                expr_var,
+                pattern_var,
+                def.exhaustive,
+                expr_con,
+            );
 
             let generalizable = Generalizable(is_generalizable_expr(&def.loc_expr.value));
 
@@ -3260,11 +3343,16 @@ fn constrain_def(
         None => {
             let expr_var = def.expr_var;
             let expr_type_index = constraints.push_variable(expr_var);
+            let pattern_var = def_pattern_var(&def.loc_pattern, expr_var, def.pattern_var);
+            let pattern_var_index = constraints.push_variable(pattern_var);
 
             let mut def_pattern_state =
-                constrain_def_pattern(types, constraints, env, &def.loc_pattern, expr_type_index);
+                constrain_def_pattern(types, constraints, env, &def.loc_pattern, pattern_var_index);
 
             def_pattern_state.vars.push(expr_var);
+            if pattern_var != expr_var {
+                def_pattern_state.vars.push(pattern_var);
+            }
             // no annotation, so no extra work with rigids
 
             let expected = constraints.push_expected_type(NoExpectation(expr_type_index));
@@ -3277,6 +3365,15 @@ fn constrain_def(
                 expected,
             );
             let expr_con = attach_resolution_constraints(constraints, env, expr_con);
+            let expr_con = constrain_destructure_exhaustive(
+                constraints,
+                &def.loc_pattern,
+                &def.loc_expr,
+                expr_var,
+                pattern_var,
+                def.exhaustive,
+                expr_con,
+            );
 
             let generalizable = Generalizable(is_generalizable_expr(&def.loc_expr.value));
 
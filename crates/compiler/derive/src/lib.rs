@@ -12,7 +12,7 @@ use roc_derive_key::DeriveKey;
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
 use roc_region::all::Loc;
 use roc_types::subs::{
-    copy_import_to, Content, Descriptor, Mark, OptVariable, Rank, Subs, Variable,
+    copy_import_to, Content, Descriptor, ExhaustiveMark, Mark, OptVariable, Rank, Subs, Variable,
 };
 use util::Env;
 
@@ -90,6 +90,8 @@ fn build_derived_body(
         expr_var: body_type,
         pattern_vars: once((derived_symbol, body_type)).collect(),
         annotation: None,
+        pattern_var: body_type,
+        exhaustive: ExhaustiveMark::known_exhaustive(),
     };
 
     (def, specialization_lambda_sets)
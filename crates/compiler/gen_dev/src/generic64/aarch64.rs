@@ -368,6 +368,9 @@ impl CallConv<AArch64GeneralReg, AArch64FloatReg, AArch64Assembler> for AArch64C
     ///  213568:       f90033fd        str     x29, [sp, #96]
     const SHADOW_SPACE_SIZE: u8 = 16;
 
+    // x15 is already reserved as a scratch register in our assembly
+    const INDIRECT_CALL_REG: AArch64GeneralReg = AArch64GeneralReg::X15;
+
     // These are registers that a called function must save and restore if it wants to use them.
     #[inline(always)]
     fn general_callee_saved(reg: &AArch64GeneralReg) -> bool {
@@ -1328,6 +1331,11 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         });
     }
 
+    #[inline(always)]
+    fn call_reg64(buf: &mut Vec<'_, u8>, src: AArch64GeneralReg) {
+        blr_reg64(buf, src)
+    }
+
     #[inline(always)]
     fn function_pointer(
         buf: &mut Vec<'_, u8>,
@@ -1370,6 +1378,23 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         });
     }
 
+    #[inline(always)]
+    fn local_data_pointer(
+        buf: &mut Vec<'_, u8>,
+        relocs: &mut Vec<'_, Relocation>,
+        data: std::vec::Vec<u8>,
+        dst: AArch64GeneralReg,
+    ) {
+        // the same `adrp` and `add` pair as `data_pointer`
+        buf.extend((0x9000_0000u32 | dst.id() as u32).to_le_bytes());
+        Self::add_reg64_reg64_imm32(buf, dst, dst, 0);
+
+        relocs.push(Relocation::LocalData {
+            offset: buf.len() as u64 - 8,
+            data,
+        });
+    }
+
     #[inline(always)]
     fn imul_reg64_reg64_reg64(
         buf: &mut Vec<'_, u8>,
@@ -3861,6 +3886,15 @@ fn subs_reg64_reg64_reg64_with_shift(
     buf.extend(inst.bytes());
 }
 
+/// `BLR Xn` -> Call the function at the address stored in Xn, setting LR to the return address.
+#[inline(always)]
+fn blr_reg64(buf: &mut Vec<'_, u8>, xn: AArch64GeneralReg) {
+    let inst =
+        UnconditionalBranchRegister::new(UnconditionalBranchRegisterParams { op: 0b01, rn: xn });
+
+    buf.extend(inst.bytes());
+}
+
 /// `RET Xn` -> Return to the address stored in Xn.
 #[inline(always)]
 fn ret_reg64(buf: &mut Vec<'_, u8>, xn: AArch64GeneralReg) {
@@ -5166,6 +5200,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_blr_reg64() {
+        disassembler_test!(
+            blr_reg64,
+            |reg1: AArch64GeneralReg| format!("blr {}", reg1.capstone_string(UsesZR)),
+            ALL_GENERAL_REGS
+        );
+    }
+
     #[test]
     fn test_ret_reg64() {
         disassembler_test!(
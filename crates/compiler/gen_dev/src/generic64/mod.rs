@@ -2,15 +2,15 @@ use crate::{
     pointer_layouts, single_register_floats, single_register_int_builtins,
     single_register_integers, Backend, Env, Relocation,
 };
-use bumpalo::collections::{CollectIn, Vec};
+use bumpalo::collections::Vec;
 use roc_builtins::bitcode::{self, FloatWidth, IntWidth};
 use roc_collections::all::MutMap;
-use roc_error_macros::{internal_error, todo_lambda_erasure};
+use roc_error_macros::internal_error;
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_mono::code_gen_help::{CallerProc, CodeGenHelp, HelperOp};
 use roc_mono::ir::{
-    BranchInfo, HigherOrderLowLevel, JoinPointId, ListLiteralElement, Literal, Param, ProcLayout,
-    SelfRecursive, Stmt,
+    BranchInfo, ErasedField, HigherOrderLowLevel, JoinPointId, ListLiteralElement, Literal, Param,
+    ProcLayout, SelfRecursive, Stmt,
 };
 use roc_mono::layout::{
     Builtin, InLayout, LambdaName, Layout, LayoutIds, LayoutInterner, LayoutRepr, STLayoutInterner,
@@ -69,6 +69,10 @@ pub trait CallConv<GeneralReg: RegTrait, FloatReg: RegTrait, ASM: Assembler<Gene
 
     const SHADOW_SPACE_SIZE: u8;
 
+    /// A caller saved register that is never used to pass arguments.
+    /// Calls by pointer load the function pointer into it once the arguments are in place.
+    const INDIRECT_CALL_REG: GeneralReg;
+
     fn general_callee_saved(reg: &GeneralReg) -> bool;
     #[inline(always)]
     fn general_caller_saved(reg: &GeneralReg) -> bool {
@@ -245,6 +249,9 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
 
     fn call(buf: &mut Vec<'_, u8>, relocs: &mut Vec<'_, Relocation>, fn_name: String);
 
+    /// Calls the function whose address is stored in `src`.
+    fn call_reg64(buf: &mut Vec<'_, u8>, src: GeneralReg);
+
     fn function_pointer(
         buf: &mut Vec<'_, u8>,
         relocs: &mut Vec<'_, Relocation>,
@@ -259,6 +266,15 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
         dst: GeneralReg,
     );
 
+    /// Loads the address of `data` into `dst`. The bytes are placed in the data section of the
+    /// object file, so they are only stored once no matter how often this code runs.
+    fn local_data_pointer(
+        buf: &mut Vec<'_, u8>,
+        relocs: &mut Vec<'_, Relocation>,
+        data: std::vec::Vec<u8>,
+        dst: GeneralReg,
+    );
+
     /// Jumps by an offset of offset bytes unconditionally.
     /// It should always generate the same number of bytes to enable replacement if offset changes.
     /// It returns the base offset to calculate the jump from (generally the instruction after the jump).
@@ -1103,6 +1119,40 @@ impl<
         self.move_return_value(dst, ret_layout)
     }
 
+    fn build_fn_call_by_pointer(
+        &mut self,
+        dst: &Symbol,
+        pointer: Symbol,
+        args: &[Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    ) {
+        // Save used caller saved regs.
+        self.storage_manager
+            .push_used_caller_saved_regs_to_stack(&mut self.buf);
+
+        // Put values in param regs or on top of the stack.
+        CC::store_args(
+            &mut self.buf,
+            &mut self.storage_manager,
+            self.layout_interner,
+            dst,
+            args,
+            arg_layouts,
+            ret_layout,
+        );
+
+        // The pointer was just saved to the stack, so it can be loaded without clobbering any args.
+        self.storage_manager.load_to_specified_general_reg(
+            &mut self.buf,
+            &pointer,
+            CC::INDIRECT_CALL_REG,
+        );
+        ASM::call_reg64(&mut self.buf, CC::INDIRECT_CALL_REG);
+
+        self.move_return_value(dst, ret_layout)
+    }
+
     fn move_return_value(&mut self, dst: &Symbol, ret_layout: &InLayout<'a>) {
         // move return value to dst.
         let ret_repr = self.interner().get_repr(*ret_layout);
@@ -3885,6 +3935,73 @@ impl<
         self.build_ptr_load(dst, ptr, element_layout)
     }
 
+    fn build_erased_make(
+        &mut self,
+        sym: &Symbol,
+        value: Option<Symbol>,
+        callee: Symbol,
+        refcounter: Option<Symbol>,
+    ) {
+        let (size, alignment) = self
+            .layout_interner
+            .stack_size_and_alignment(Layout::ERASED);
+        let base_offset = self
+            .storage_manager
+            .claim_stack_area_with_alignment(*sym, size, alignment);
+
+        // the erased value is a null pointer if the function captures nothing
+        match value {
+            Some(value) => {
+                let reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, &value);
+                ASM::mov_base32_reg64(&mut self.buf, base_offset, reg);
+            }
+            None => {
+                self.storage_manager
+                    .with_tmp_general_reg(&mut self.buf, |_, buf, reg| {
+                        ASM::mov_reg64_imm64(buf, reg, 0);
+                        ASM::mov_base32_reg64(buf, base_offset, reg);
+                    });
+            }
+        }
+
+        let reg = self
+            .storage_manager
+            .load_to_general_reg(&mut self.buf, &callee);
+        ASM::mov_base32_reg64(&mut self.buf, base_offset + 8, reg);
+
+        // like the value, the refcounter is a null pointer if the function captures nothing
+        match refcounter {
+            Some(refcounter) => {
+                let reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, &refcounter);
+                ASM::mov_base32_reg64(&mut self.buf, base_offset + 16, reg);
+            }
+            None => {
+                self.storage_manager
+                    .with_tmp_general_reg(&mut self.buf, |_, buf, reg| {
+                        ASM::mov_reg64_imm64(buf, reg, 0);
+                        ASM::mov_base32_reg64(buf, base_offset + 16, reg);
+                    });
+            }
+        }
+    }
+
+    fn build_erased_load(&mut self, sym: &Symbol, erased: Symbol, field: ErasedField) {
+        let offset = match field {
+            ErasedField::Value | ErasedField::ValuePtr => 0,
+            ErasedField::Callee => 8,
+            ErasedField::Refcounter => 16,
+        };
+
+        let (base_offset, _) = self.storage_manager.stack_offset_and_size(&erased);
+
+        let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
+        ASM::mov_reg64_base32(&mut self.buf, dst_reg, base_offset + offset);
+    }
+
     fn get_tag_id(&mut self, sym: &Symbol, structure: &Symbol, union_layout: &UnionLayout<'a>) {
         let layout_interner: &mut STLayoutInterner<'a> = self.layout_interner;
         let _buf: &mut Vec<'a, u8> = &mut self.buf;
@@ -3937,6 +4054,57 @@ impl<
 
                 self.free_symbol(&tmp);
             }
+            UnionLayout::NullableWrapped { nullable_id, .. }
+                if union_layout.stores_tag_id_as_data(self.storage_manager.target) =>
+            {
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
+                let ptr_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, structure);
+
+                // a null pointer is the nullable tag
+                ASM::mov_reg64_imm64(&mut self.buf, dst_reg, *nullable_id as i64);
+
+                // any other pointer stores its tag id in the data; jump over the exit when non-null
+                let jne_start_index = self.buf.len();
+                let jne_end_index = ASM::jne_reg64_imm64_imm32(
+                    &mut self.buf,
+                    &mut self.storage_manager,
+                    ptr_reg,
+                    0x0,
+                    0,
+                );
+
+                let jmp_start_index = self.buf.len();
+                let jmp_end_index = ASM::jmp_imm32(&mut self.buf, JUMP_PLACEHOLDER);
+
+                Self::load_tag_id_from_data(
+                    &mut self.buf,
+                    self.layout_interner,
+                    dst_reg,
+                    ptr_reg,
+                    union_layout,
+                );
+
+                let destination_index = self.buf.len();
+                ASM::update_jmp_imm32_offset(
+                    &mut self.buf,
+                    jmp_start_index as u64,
+                    jmp_end_index as u64,
+                    destination_index as u64,
+                );
+
+                // update the jump to skip over the null case
+                let mut tmp = bumpalo::vec![in self.env.arena];
+                ASM::jne_reg64_imm64_imm32(
+                    &mut tmp,
+                    &mut self.storage_manager,
+                    ptr_reg,
+                    0x0,
+                    (jmp_end_index - jne_end_index) as i32,
+                );
+                self.buf[jne_start_index..][..tmp.len()].copy_from_slice(tmp.as_slice());
+            }
             UnionLayout::NullableWrapped {
                 nullable_id,
                 other_tags,
@@ -4009,23 +4177,17 @@ impl<
 
                 let target = self.storage_manager.target;
                 if union_layout.stores_tag_id_as_data(target) {
-                    let offset = union_layout.tag_id_offset(self.interner()).unwrap() as i32;
-
                     let ptr_reg = self
                         .storage_manager
                         .load_to_general_reg(&mut self.buf, structure);
 
-                    match union_layout.tag_id_layout() {
-                        Layout::U8 => {
-                            ASM::mov_reg8_mem8_offset32(&mut self.buf, dst_reg, ptr_reg, offset);
-                            ASM::movzx_reg_reg(&mut self.buf, RegisterWidth::W8, dst_reg, dst_reg)
-                        }
-                        Layout::U16 => {
-                            ASM::mov_reg16_mem16_offset32(&mut self.buf, dst_reg, ptr_reg, offset);
-                            ASM::movzx_reg_reg(&mut self.buf, RegisterWidth::W16, dst_reg, dst_reg)
-                        }
-                        _ => unreachable!(),
-                    }
+                    Self::load_tag_id_from_data(
+                        &mut self.buf,
+                        self.layout_interner,
+                        dst_reg,
+                        ptr_reg,
+                        union_layout,
+                    );
                 } else {
                     // mask the 3 lowest bits
                     let tmp = Symbol::DEV_TMP5;
@@ -4546,7 +4708,7 @@ impl<
 
                             let mut bytes = [0; 24];
                             bytes[..x.len()].copy_from_slice(x.as_bytes());
-                            bytes[23] = (x.len() as u8) | 0b1000_0000;
+                            bytes[23] = (x.len() as u8) | roc_std::RocStr::MASK;
 
                             let mut num_bytes = [0; 8];
                             num_bytes.copy_from_slice(&bytes[..8]);
@@ -4566,15 +4728,32 @@ impl<
                         },
                     );
                 } else {
-                    // load large string (pretend it's a `List U8`). We should move this data into
-                    // the binary eventually because our RC algorithm won't free this value
-                    let elements: Vec<_> = x
-                        .as_bytes()
-                        .iter()
-                        .map(|b| ListLiteralElement::Literal(Literal::Byte(*b)))
-                        .collect_in(self.storage_manager.env.arena);
+                    // Load large string. The bytes live in the data section, preceded by a
+                    // refcount of 0 which marks them as read-only, so they are never freed.
+                    let mut data = std::vec::Vec::with_capacity(8 + x.len());
+                    data.extend(0u64.to_le_bytes());
+                    data.extend(x.as_bytes());
 
-                    self.create_array(sym, &Layout::U8, elements.into_bump_slice())
+                    self.storage_manager.with_tmp_general_reg(
+                        &mut self.buf,
+                        |storage_manager, buf, reg| {
+                            let base_offset = storage_manager.claim_stack_area_layout(
+                                self.layout_interner,
+                                *sym,
+                                Layout::STR,
+                            );
+
+                            // skip over the refcount to get to the first byte
+                            ASM::local_data_pointer(buf, &mut self.relocs, data, reg);
+                            ASM::add_reg64_reg64_imm32(buf, reg, reg, 8);
+                            ASM::mov_base32_reg64(buf, base_offset, reg);
+
+                            // the length and capacity are both the number of bytes
+                            ASM::mov_reg64_imm64(buf, reg, x.len() as i64);
+                            ASM::mov_base32_reg64(buf, base_offset + 8, reg);
+                            ASM::mov_base32_reg64(buf, base_offset + 16, reg);
+                        },
+                    );
                 }
             }
             _ => todo!("loading literal {:?} with layout {:?}", lit, layout),
@@ -5247,6 +5426,29 @@ impl<
         self.buf[mov_start_index..][..tmp.len()].copy_from_slice(tmp.as_slice());
     }
 
+    /// Loads the tag id of a union that stores its tag id in the data that `ptr_reg` points to.
+    fn load_tag_id_from_data(
+        buf: &mut Vec<'a, u8>,
+        layout_interner: &STLayoutInterner<'a>,
+        dst_reg: GeneralReg,
+        ptr_reg: GeneralReg,
+        union_layout: &UnionLayout<'a>,
+    ) {
+        let offset = union_layout.tag_id_offset(layout_interner).unwrap() as i32;
+
+        match union_layout.tag_id_layout() {
+            Layout::U8 => {
+                ASM::mov_reg8_mem8_offset32(buf, dst_reg, ptr_reg, offset);
+                ASM::movzx_reg_reg(buf, RegisterWidth::W8, dst_reg, dst_reg)
+            }
+            Layout::U16 => {
+                ASM::mov_reg16_mem16_offset32(buf, dst_reg, ptr_reg, offset);
+                ASM::movzx_reg_reg(buf, RegisterWidth::W16, dst_reg, dst_reg)
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn unbox_str_or_list(
         buf: &mut Vec<'a, u8>,
         storage_manager: &mut StorageManager<'a, 'r, GeneralReg, FloatReg, ASM, CC>,
@@ -5471,7 +5673,22 @@ impl<
                 );
             }
 
-            LayoutRepr::Erased(_) => todo_lambda_erasure!(),
+            LayoutRepr::Erased(_) => {
+                // put it on the stack, like a struct of three pointers
+                let stack_size = layout_interner.stack_size(element_in_layout);
+
+                storage_manager.with_tmp_general_reg(buf, |storage_manager, buf, tmp_reg| {
+                    Self::unbox_to_stack(
+                        buf,
+                        storage_manager,
+                        dst,
+                        stack_size,
+                        ptr_reg,
+                        tmp_reg,
+                        offset,
+                    );
+                });
+            }
         }
     }
 
@@ -201,6 +201,7 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
         X86_64FloatReg::XMM0,
     ];
     const SHADOW_SPACE_SIZE: u8 = 0;
+    const INDIRECT_CALL_REG: X86_64GeneralReg = X86_64GeneralReg::R11;
 
     // These are registers that a called function must save and restore if it wants to use them.
     #[inline(always)]
@@ -1412,6 +1413,7 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Windo
         X86_64FloatReg::XMM0,
     ];
     const SHADOW_SPACE_SIZE: u8 = 32;
+    const INDIRECT_CALL_REG: X86_64GeneralReg = X86_64GeneralReg::R11;
 
     // These are registers that a called function must save and restore if it wants to use them.
     //
@@ -2067,6 +2069,11 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
         });
     }
 
+    #[inline(always)]
+    fn call_reg64(buf: &mut Vec<'_, u8>, src: X86_64GeneralReg) {
+        call_reg64(buf, src);
+    }
+
     #[inline(always)]
     fn function_pointer(
         buf: &mut Vec<'_, u8>,
@@ -2101,6 +2108,21 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
         X86_64Assembler::mov_reg64_mem64_offset32(buf, dst, dst, 0);
     }
 
+    #[inline(always)]
+    fn local_data_pointer(
+        buf: &mut Vec<'_, u8>,
+        relocs: &mut Vec<'_, Relocation>,
+        data: std::vec::Vec<u8>,
+        dst: X86_64GeneralReg,
+    ) {
+        lea_reg64(buf, dst);
+
+        relocs.push(Relocation::LocalData {
+            offset: buf.len() as u64 - 4,
+            data,
+        });
+    }
+
     #[inline(always)]
     fn imul_reg64_reg64_reg64(
         buf: &mut Vec<'_, u8>,
@@ -3429,6 +3451,20 @@ fn jmp_imm32(buf: &mut Vec<'_, u8>, imm: i32) {
     buf.extend(imm.to_le_bytes());
 }
 
+/// `CALL r/m64` -> Call near, absolute indirect, address given in r/m64.
+#[inline(always)]
+fn call_reg64(buf: &mut Vec<'_, u8>, src: X86_64GeneralReg) {
+    let src_mod = src as u8 % 8;
+    if src as u8 > 7 {
+        let rex = add_rm_extension(src, REX);
+        #[allow(clippy::unusual_byte_groupings)]
+        buf.extend([rex, 0xFF, 0b11_010_000 | src_mod]);
+    } else {
+        #[allow(clippy::unusual_byte_groupings)]
+        buf.extend([0xFF, 0b11_010_000 | src_mod]);
+    }
+}
+
 #[inline(always)]
 fn jmp_reg64_offset8(buf: &mut Vec<'_, u8>, base: X86_64GeneralReg, offset: i8) {
     let rex = add_rm_extension(base, REX_W);
@@ -4702,6 +4738,11 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_call_reg64() {
+        disassembler_test!(call_reg64, |reg| format!("call {reg}"), ALL_GENERAL_REGS);
+    }
+
     #[test]
     fn test_jmp_reg64_offset8() {
         disassembler_test!(
@@ -10,14 +10,14 @@ use std::collections::hash_map::Entry;
 use bumpalo::{collections::Vec, Bump};
 use roc_builtins::bitcode::{self, FloatWidth, IntWidth};
 use roc_collections::all::{MutMap, MutSet};
-use roc_error_macros::{internal_error, todo_lambda_erasure};
+use roc_error_macros::internal_error;
 use roc_module::ident::ModuleName;
 use roc_module::low_level::{LowLevel, LowLevelWrapperType};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
-use roc_mono::code_gen_help::{CallerProc, CodeGenHelp};
+use roc_mono::code_gen_help::{CallerProc, CodeGenHelp, HelperOp};
 use roc_mono::ir::{
-    BranchInfo, CallType, CrashTag, Expr, HigherOrderLowLevel, JoinPointId, ListLiteralElement,
-    Literal, ModifyRc, Param, Proc, ProcLayout, SelfRecursive, Stmt,
+    BranchInfo, CallType, CrashTag, ErasedField, Expr, HigherOrderLowLevel, JoinPointId,
+    ListLiteralElement, Literal, ModifyRc, Param, Proc, ProcLayout, SelfRecursive, Stmt,
 };
 use roc_mono::layout::{
     Builtin, InLayout, LambdaName, Layout, LayoutIds, LayoutInterner, LayoutRepr, STLayoutInterner,
@@ -208,8 +208,7 @@ impl<'a> LastSeenMap<'a> {
                             self.set_last_seen(*initializer, stmt);
                         }
                     }
-                    Expr::RuntimeErrorFunction(_) => {}
-                    Expr::FunctionPointer { .. } => todo_lambda_erasure!(),
+                    Expr::FunctionPointer { .. } => {}
                     Expr::EmptyArray => {}
                 }
                 self.scan_ast_help(following);
@@ -298,7 +297,9 @@ impl<'a> LastSeenMap<'a> {
 
         match call_type {
             CallType::ByName { .. } => {}
-            CallType::ByPointer { .. } => {}
+            CallType::ByPointer { pointer, .. } => {
+                self.set_last_seen(*pointer, stmt);
+            }
             CallType::LowLevel { .. } => {}
             CallType::HigherOrder { .. } => {}
             CallType::Foreign { .. } => {}
@@ -455,6 +456,32 @@ trait Backend<'a> {
         element_decrement
     }
 
+    /// The refcounter of an erased value points to the proc that decrements its boxed captures.
+    /// Incrementing does not need to know what the captures are, but freeing them does.
+    fn erased_refcounter_fn_pointer(&mut self, value_layout: InLayout<'a>) -> Symbol {
+        let refcounter = self.debug_symbol("erased_refcounter");
+
+        let (module_id, layout_interner, interns, rc_proc_gen, _) =
+            self.module_interns_helpers_mut();
+        let ident_ids = interns.all_ident_ids.get_mut(&module_id).unwrap();
+
+        let (refcount_proc_name, linker_data) =
+            rc_proc_gen.gen_refcount_proc(ident_ids, layout_interner, value_layout, HelperOp::Dec);
+
+        self.helper_proc_symbols_mut().extend(linker_data);
+
+        let refcounter_string = self.lambda_name_to_string(
+            LambdaName::no_niche(refcount_proc_name),
+            [value_layout].into_iter(),
+            None,
+            Layout::UNIT,
+        );
+
+        self.build_fn_pointer(&refcounter, refcounter_string);
+
+        refcounter
+    }
+
     fn helper_proc_gen_mut(&mut self) -> &mut CodeGenHelp<'a>;
 
     fn helper_proc_symbols_mut(&mut self) -> &mut Vec<'a, (Symbol, ProcLayout<'a>)>;
@@ -772,8 +799,19 @@ trait Backend<'a> {
                         self.build_fn_call(sym, fn_name, arguments, arg_layouts, ret_layout)
                     }
 
-                    CallType::ByPointer { .. } => {
-                        todo_lambda_erasure!()
+                    CallType::ByPointer {
+                        pointer,
+                        arg_layouts,
+                        ret_layout,
+                    } => {
+                        self.load_literal_symbols(arguments);
+                        self.build_fn_call_by_pointer(
+                            sym,
+                            *pointer,
+                            arguments,
+                            arg_layouts,
+                            ret_layout,
+                        )
                     }
 
                     CallType::LowLevel { op: lowlevel, .. } => {
@@ -893,9 +931,36 @@ trait Backend<'a> {
             Expr::NullPointer => {
                 self.load_literal_i64(sym, 0);
             }
-            Expr::FunctionPointer { .. } => todo_lambda_erasure!(),
-            Expr::ErasedMake { .. } => todo_lambda_erasure!(),
-            Expr::ErasedLoad { .. } => todo_lambda_erasure!(),
+            Expr::FunctionPointer { lambda_name } => {
+                let function_pointer = match self.interner().get_repr(*layout) {
+                    LayoutRepr::FunctionPointer(function_pointer) => function_pointer,
+                    other => internal_error!("function pointer with non-pointer layout: {other:?}"),
+                };
+
+                let fn_name = self.lambda_name_to_string(
+                    *lambda_name,
+                    function_pointer.args.iter().copied(),
+                    None,
+                    function_pointer.ret,
+                );
+
+                self.build_fn_pointer(sym, fn_name);
+            }
+            Expr::ErasedMake { value, callee } => {
+                let refcounter = value.map(|value| {
+                    let value_layout = *self.layout_map().get(&value).unwrap();
+                    self.erased_refcounter_fn_pointer(value_layout)
+                });
+
+                self.build_erased_make(sym, *value, *callee, refcounter);
+
+                if let Some(refcounter) = refcounter {
+                    self.free_symbol(&refcounter);
+                }
+            }
+            Expr::ErasedLoad { symbol, field } => {
+                self.build_erased_load(sym, *symbol, *field);
+            }
             Expr::Reset { symbol, .. } => {
                 let layout = *self.layout_map().get(symbol).unwrap();
 
@@ -942,7 +1007,6 @@ trait Backend<'a> {
             } => {
                 self.build_alloca(*sym, *initializer, *element_layout);
             }
-            Expr::RuntimeErrorFunction(_) => todo!(),
         }
     }
 
@@ -1195,10 +1259,13 @@ trait Backend<'a> {
                     // saturated sub is just normal sub
                     self.build_num_sub(sym, &args[0], &args[1], ret_layout)
                 }
-                LayoutRepr::Builtin(Builtin::Decimal) => {
-                    // self.load_args_and_call_zig(backend, bitcode::DEC_SUB_SATURATED)
-                    todo!()
-                }
+                LayoutRepr::Builtin(Builtin::Decimal) => self.build_fn_call(
+                    sym,
+                    bitcode::DEC_SUB_SATURATED.to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                ),
                 _ => internal_error!("invalid return type"),
             },
             LowLevel::NumBitwiseAnd => {
@@ -2167,6 +2234,16 @@ trait Backend<'a> {
         ret_layout: &InLayout<'a>,
     );
 
+    /// build_fn_call_by_pointer creates a call site for a function whose address is stored in `pointer`.
+    fn build_fn_call_by_pointer(
+        &mut self,
+        dst: &Symbol,
+        pointer: Symbol,
+        args: &[Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    );
+
     fn build_fn_pointer(&mut self, dst: &Symbol, fn_name: String);
     fn build_data_pointer(&mut self, dst: &Symbol, data_name: String);
 
@@ -2638,6 +2715,19 @@ trait Backend<'a> {
         reuse: Option<Symbol>,
     );
 
+    /// build_erased_make packs captures, a function pointer and the refcounter of the captures
+    /// into a type-erased value.
+    fn build_erased_make(
+        &mut self,
+        sym: &Symbol,
+        value: Option<Symbol>,
+        callee: Symbol,
+        refcounter: Option<Symbol>,
+    );
+
+    /// build_erased_load loads a pointer field out of a type-erased value.
+    fn build_erased_load(&mut self, sym: &Symbol, erased: Symbol, field: ErasedField);
+
     /// return_symbol moves a symbol to the correct return location for the backend and adds a jump to the end of the function.
     fn return_symbol(&mut self, sym: &Symbol, layout: &InLayout<'a>);
 
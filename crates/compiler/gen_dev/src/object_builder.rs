@@ -954,14 +954,67 @@ fn build_proc<'a, B: Backend<'a>>(
                 };
                 local_data_index += 1;
                 let data_id = output.add_symbol(data_symbol);
-                output.add_symbol_data(data_id, data_section, data, 4);
-                write::Relocation {
-                    offset: offset + proc_offset,
-                    size: 32,
-                    kind: RelocationKind::Relative,
-                    encoding: RelocationEncoding::Generic,
-                    symbol: data_id,
-                    addend: -4,
+                // Large string literals start with an 8-byte refcount that is read and compared
+                // like any other refcount, and f64 constants are 8 bytes too. Aligning every
+                // constant to 8 costs at most a few bytes of padding per constant.
+                output.add_symbol_data(data_id, data_section, data, 8);
+
+                if cfg!(all(target_arch = "aarch64", target_os = "linux")) {
+                    // an `adrp` of the page, then an `add` of the offset within that page
+                    let r = write::Relocation {
+                        offset: proc_offset + offset,
+                        size: 21,
+                        kind: RelocationKind::Elf(object::elf::R_AARCH64_ADR_PREL_PG_HI21),
+                        encoding: RelocationEncoding::Generic,
+                        symbol: data_id,
+                        addend: 0,
+                    };
+
+                    relocations.push((section_id, r));
+
+                    write::Relocation {
+                        offset: proc_offset + offset + 4,
+                        size: 12,
+                        kind: RelocationKind::Elf(object::elf::R_AARCH64_ADD_ABS_LO12_NC),
+                        encoding: RelocationEncoding::Generic,
+                        symbol: data_id,
+                        addend: 0,
+                    }
+                } else if cfg!(all(target_arch = "aarch64", target_os = "macos")) {
+                    let r = write::Relocation {
+                        offset: proc_offset + offset,
+                        size: 21,
+                        kind: RelocationKind::MachO {
+                            value: object::macho::ARM64_RELOC_PAGE21,
+                            relative: true,
+                        },
+                        encoding: RelocationEncoding::Generic,
+                        symbol: data_id,
+                        addend: 0,
+                    };
+
+                    relocations.push((section_id, r));
+
+                    write::Relocation {
+                        offset: proc_offset + offset + 4,
+                        size: 12,
+                        kind: RelocationKind::MachO {
+                            value: object::macho::ARM64_RELOC_PAGEOFF12,
+                            relative: false,
+                        },
+                        encoding: RelocationEncoding::Generic,
+                        symbol: data_id,
+                        addend: 0,
+                    }
+                } else {
+                    write::Relocation {
+                        offset: offset + proc_offset,
+                        size: 32,
+                        kind: RelocationKind::Relative,
+                        encoding: RelocationEncoding::Generic,
+                        symbol: data_id,
+                        addend: -4,
+                    }
                 }
             }
             Relocation::LinkedData { offset, name } => {
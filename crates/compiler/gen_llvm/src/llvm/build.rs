@@ -58,8 +58,8 @@ use std::path::Path;
 
 use super::convert::{struct_type_from_union_layout, RocUnion};
 use super::intrinsics::{
-    add_intrinsics, LLVM_FRAME_ADDRESS, LLVM_MEMSET_I32, LLVM_MEMSET_I64, LLVM_SETJMP,
-    LLVM_STACK_SAVE,
+    add_intrinsics, LLVM_EXPECT_I1, LLVM_FRAME_ADDRESS, LLVM_MEMSET_I32, LLVM_MEMSET_I64,
+    LLVM_SETJMP, LLVM_STACK_SAVE,
 };
 use super::lowlevel::run_higher_order_low_level;
 use super::scope::Scope;
@@ -739,6 +739,10 @@ pub struct Env<'a, 'ctx, 'env> {
     pub target: Target,
     pub mode: LlvmBackendMode,
     pub exposed_to_host: MutSet<Symbol>,
+    /// Give roc procs inline stack probes (x86 only)
+    pub probe_stack: bool,
+    /// Panic with "stack overflow" once roc procs use more than this many bytes of stack
+    pub stack_limit: Option<u32>,
 }
 
 impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
@@ -1175,6 +1179,31 @@ pub fn construct_optimization_passes<'a>(
     (mpm, fpm)
 }
 
+/// Make the module easy to profile: keep frame pointers so the stack can be walked, keep
+/// calls to `roc_panic` apart so each sample points at its own check, and give every
+/// function a symbol so samples are attributed to real names.
+pub fn keep_frame_pointers(module: &Module<'_>) {
+    let context = module.get_context();
+    let frame_pointer = context.create_string_attribute("frame-pointer", "all");
+
+    for function in module.get_functions() {
+        function.add_attribute(AttributeLoc::Function, frame_pointer);
+
+        // private functions get no symbol at all, internal ones get a local symbol
+        if function.get_linkage() == Linkage::Private {
+            function.set_linkage(Linkage::Internal);
+        }
+    }
+
+    if let Some(roc_panic) = module.get_function("roc_panic") {
+        let kind_id = Attribute::get_named_enum_kind_id("nomerge");
+        debug_assert!(kind_id > 0);
+        let nomerge = context.create_enum_attribute(kind_id, 0);
+
+        roc_panic.add_attribute(AttributeLoc::Function, nomerge);
+    }
+}
+
 fn promote_to_main_function<'a, 'ctx>(
     env: &Env<'a, 'ctx, '_>,
     layout_interner: &STLayoutInterner<'a>,
@@ -1359,7 +1388,7 @@ pub fn build_exp_literal<'a, 'ctx>(
             LayoutRepr::Builtin(Builtin::Int(int_width)) => {
                 int_with_precision(env, i128::from_ne_bytes(*bytes), int_width).into()
             }
-            _ => panic!("Invalid layout for int literal = {layout:?}"),
+            _ => internal_error!("Invalid layout for int literal = {layout:?}"),
         },
 
         U128(bytes) => const_u128(env, u128::from_ne_bytes(*bytes)).into(),
@@ -1368,7 +1397,7 @@ pub fn build_exp_literal<'a, 'ctx>(
             LayoutRepr::Builtin(Builtin::Float(float_width)) => {
                 float_with_precision(env, *float, float_width)
             }
-            _ => panic!("Invalid layout for float literal = {layout:?}"),
+            _ => internal_error!("Invalid layout for float literal = {layout:?}"),
         },
 
         Decimal(bytes) => {
@@ -1436,23 +1465,7 @@ fn small_str_ptr_width_8<'ctx>(
 ) -> PointerValue<'ctx> {
     debug_assert_eq!(env.target.ptr_width() as u8, 8);
 
-    let mut array = [0u8; 24];
-
-    array[..str_literal.len()].copy_from_slice(str_literal.as_bytes());
-
-    array[env.small_str_bytes() as usize - 1] = str_literal.len() as u8 | roc_std::RocStr::MASK;
-
-    let word1 = u64::from_ne_bytes(array[0..8].try_into().unwrap());
-    let word2 = u64::from_ne_bytes(array[8..16].try_into().unwrap());
-    let word3 = u64::from_ne_bytes(array[16..24].try_into().unwrap());
-
-    let ptr = env.ptr_int().const_int(word1, false);
-    let len = env.ptr_int().const_int(word2, false);
-    let cap = env.ptr_int().const_int(word3, false);
-
-    let address_space = AddressSpace::default();
-    let ptr_type = env.context.i8_type().ptr_type(address_space);
-    let ptr = env.builder.new_build_int_to_ptr(ptr, ptr_type, "to_u8_ptr");
+    let (ptr, len, cap) = small_str_words(env, str_literal);
 
     const_str_alloca_ptr(env, parent, ptr, len, cap)
 }
@@ -1460,29 +1473,46 @@ fn small_str_ptr_width_8<'ctx>(
 fn small_str_ptr_width_4<'ctx>(env: &Env<'_, 'ctx, '_>, str_literal: &str) -> StructValue<'ctx> {
     debug_assert_eq!(env.target.ptr_width() as u8, 4);
 
-    let mut array = [0u8; 12];
+    let (ptr, len, cap) = small_str_words(env, str_literal);
+
+    struct_from_fields(
+        env,
+        zig_str_type(env),
+        [(0, ptr.into()), (1, len.into()), (2, cap.into())].into_iter(),
+    )
+}
+
+/// The three pointer-sized words of a small string: the bytes of the string, followed by
+/// padding, with the length and the small string flag in the final byte.
+fn small_str_words<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    str_literal: &str,
+) -> (PointerValue<'ctx>, IntValue<'ctx>, IntValue<'ctx>) {
+    let small_str_bytes = env.small_str_bytes() as usize;
+    let word_bytes = env.target.ptr_width() as usize;
+
+    debug_assert!(str_literal.len() < small_str_bytes);
+
+    let mut array = [0u8; 24];
 
     array[..str_literal.len()].copy_from_slice(str_literal.as_bytes());
 
-    array[env.small_str_bytes() as usize - 1] = str_literal.len() as u8 | roc_std::RocStr::MASK;
+    array[small_str_bytes - 1] = str_literal.len() as u8 | roc_std::RocStr::MASK;
 
-    let word1 = u32::from_ne_bytes(array[0..4].try_into().unwrap());
-    let word2 = u32::from_ne_bytes(array[4..8].try_into().unwrap());
-    let word3 = u32::from_ne_bytes(array[8..12].try_into().unwrap());
+    let word = |index: usize| {
+        let mut bytes = [0u8; 8];
+        bytes[..word_bytes].copy_from_slice(&array[index * word_bytes..][..word_bytes]);
 
-    let ptr = env.ptr_int().const_int(word1 as u64, false);
-    let len = env.ptr_int().const_int(word2 as u64, false);
-    let cap = env.ptr_int().const_int(word3 as u64, false);
+        env.ptr_int().const_int(u64::from_le_bytes(bytes), false)
+    };
 
     let address_space = AddressSpace::default();
     let ptr_type = env.context.i8_type().ptr_type(address_space);
-    let ptr = env.builder.new_build_int_to_ptr(ptr, ptr_type, "to_u8_ptr");
+    let ptr = env
+        .builder
+        .new_build_int_to_ptr(word(0), ptr_type, "to_u8_ptr");
 
-    struct_from_fields(
-        env,
-        zig_str_type(env),
-        [(0, ptr.into()), (1, len.into()), (2, cap.into())].into_iter(),
-    )
+    (ptr, word(1), word(2))
 }
 
 pub(crate) fn build_exp_call<'a, 'ctx>(
@@ -1918,8 +1948,6 @@ pub(crate) fn build_exp_expr<'a, 'ctx>(
         Array { elem_layout, elems } => {
             list_literal(env, layout_interner, parent, scope, *elem_layout, elems)
         }
-        RuntimeErrorFunction(_) => todo!(),
-
         UnionAtIndex {
             tag_id,
             structure,
@@ -2952,7 +2980,6 @@ fn list_literal<'a, 'ctx>(
     element_layout: InLayout<'a>,
     elems: &[ListLiteralElement],
 ) -> BasicValueEnum<'ctx> {
-    let ctx = env.context;
     let builder = env.builder;
 
     let element_type = basic_type_from_layout(
@@ -3084,7 +3111,7 @@ fn list_literal<'a, 'ctx>(
 
             // then replace the `undef`s with the values that we evaluate at runtime
             for (index, val) in runtime_evaluated_elements {
-                let index_val = ctx.i64_type().const_int(index as u64, false);
+                let index_val = env.ptr_int().const_int(index as u64, false);
                 let elem_ptr = unsafe {
                     builder.new_build_in_bounds_gep(element_type, ptr, &[index_val], "index")
                 };
@@ -3105,7 +3132,7 @@ fn list_literal<'a, 'ctx>(
                 }
                 ListLiteralElement::Symbol(symbol) => scope.load_symbol(symbol),
             };
-            let index_val = ctx.i64_type().const_int(index as u64, false);
+            let index_val = env.ptr_int().const_int(index as u64, false);
             let elem_ptr = unsafe {
                 builder.new_build_in_bounds_gep(element_type, ptr, &[index_val], "index")
             };
@@ -4107,6 +4134,35 @@ fn const_u128<'ctx>(env: &Env<'_, 'ctx, '_>, value: u128) -> IntValue<'ctx> {
         .const_int_arbitrary_precision(&[a, b])
 }
 
+/// The weights LLVM itself gives to `llvm.expect` branches
+const HOT_BRANCH_WEIGHT: u64 = 2000;
+const COLD_BRANCH_WEIGHT: u64 = 1;
+
+/// A branch that always ends in a crash, e.g. a `crash` or a non-exhaustive pattern match
+fn is_cold_branch(mut stmt: &roc_mono::ir::Stmt<'_>) -> bool {
+    use roc_mono::ir::Stmt::*;
+
+    loop {
+        match stmt {
+            Let(_, _, _, cont) | Refcounting(_, cont) => stmt = cont,
+            Crash(..) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// Tell LLVM which value `condition` is likely to have
+pub(crate) fn expect_bool<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    condition: IntValue<'ctx>,
+    expected: bool,
+) -> IntValue<'ctx> {
+    let expected = env.context.bool_type().const_int(expected as u64, false);
+
+    env.call_intrinsic(LLVM_EXPECT_I1, &[condition.into(), expected.into()])
+        .into_int_value()
+}
+
 fn build_switch_ir<'a, 'ctx>(
     env: &Env<'a, 'ctx, '_>,
     layout_interner: &STLayoutInterner<'a>,
@@ -4173,55 +4229,66 @@ fn build_switch_ir<'a, 'ctx>(
     // Build the cases
     let mut incoming = Vec::with_capacity_in(branches.len(), arena);
 
-    if let LayoutRepr::Builtin(Builtin::Bool) = layout_interner.get_repr(cond_layout) {
-        match (branches, default_branch) {
-            ([(0, _, false_branch)], true_branch) | ([(1, _, true_branch)], false_branch) => {
-                let then_block = context.append_basic_block(parent, "then_block");
-                let else_block = context.append_basic_block(parent, "else_block");
-
-                builder.new_build_conditional_branch(cond, then_block, else_block);
+    // A bool with one explicit branch becomes a conditional branch. Any other shape of bool
+    // switch (e.g. both values listed) goes through the general `switch` lowering below.
+    let bool_branches = match (layout_interner.get_repr(cond_layout), branches) {
+        (LayoutRepr::Builtin(Builtin::Bool), [(0, _, false_branch)]) => {
+            Some((default_branch, false_branch))
+        }
+        (LayoutRepr::Builtin(Builtin::Bool), [(1, _, true_branch)]) => {
+            Some((true_branch, default_branch))
+        }
+        _ => None,
+    };
 
-                {
-                    builder.position_at_end(then_block);
+    if let Some((true_branch, false_branch)) = bool_branches {
+        let then_block = context.append_basic_block(parent, "then_block");
+        let else_block = context.append_basic_block(parent, "else_block");
 
-                    let branch_val = build_exp_stmt(
-                        env,
-                        layout_interner,
-                        layout_ids,
-                        func_spec_solutions,
-                        scope,
-                        parent,
-                        true_branch,
-                    );
+        // let LLVM lay out the branch that does not crash as the fall-through
+        let cond = match (is_cold_branch(true_branch), is_cold_branch(false_branch)) {
+            (true, false) => expect_bool(env, cond, false),
+            (false, true) => expect_bool(env, cond, true),
+            _ => cond,
+        };
 
-                    if then_block.get_terminator().is_none() {
-                        builder.new_build_unconditional_branch(cont_block);
-                        incoming.push((branch_val, then_block));
-                    }
-                }
+        builder.new_build_conditional_branch(cond, then_block, else_block);
 
-                {
-                    builder.position_at_end(else_block);
+        {
+            builder.position_at_end(then_block);
 
-                    let branch_val = build_exp_stmt(
-                        env,
-                        layout_interner,
-                        layout_ids,
-                        func_spec_solutions,
-                        scope,
-                        parent,
-                        false_branch,
-                    );
+            let branch_val = build_exp_stmt(
+                env,
+                layout_interner,
+                layout_ids,
+                func_spec_solutions,
+                scope,
+                parent,
+                true_branch,
+            );
 
-                    if else_block.get_terminator().is_none() {
-                        builder.new_build_unconditional_branch(cont_block);
-                        incoming.push((branch_val, else_block));
-                    }
-                }
+            if then_block.get_terminator().is_none() {
+                builder.new_build_unconditional_branch(cont_block);
+                incoming.push((branch_val, then_block));
             }
+        }
 
-            _ => {
-                unreachable!()
+        {
+            builder.position_at_end(else_block);
+
+            let branch_val = build_exp_stmt(
+                env,
+                layout_interner,
+                layout_ids,
+                func_spec_solutions,
+                scope,
+                parent,
+                false_branch,
+            );
+
+            if else_block.get_terminator().is_none() {
+                builder.new_build_unconditional_branch(cont_block);
+                incoming.push((branch_val, else_block));
             }
         }
     } else {
@@ -4252,7 +4319,39 @@ fn build_switch_ir<'a, 'ctx>(
             cases.push((int_val, block));
         }
 
-        builder.new_build_switch(cond, default_block, &cases);
+        let switch = builder.new_build_switch(cond, default_block, &cases);
+
+        // weigh the branches that crash as unlikely, so LLVM lays out the others as the hot path
+        let default_is_cold = is_cold_branch(default_branch);
+        let any_cold =
+            default_is_cold || branches.iter().any(|(_, _, branch)| is_cold_branch(branch));
+        let all_cold =
+            default_is_cold && branches.iter().all(|(_, _, branch)| is_cold_branch(branch));
+
+        if any_cold && !all_cold {
+            let weight = |cold: bool| {
+                let weight = if cold {
+                    COLD_BRANCH_WEIGHT
+                } else {
+                    HOT_BRANCH_WEIGHT
+                };
+
+                BasicMetadataValueEnum::from(context.i32_type().const_int(weight, false))
+            };
+
+            let mut weights = Vec::with_capacity_in(branches.len() + 2, arena);
+            weights.push(context.metadata_string("branch_weights").into());
+            weights.push(weight(default_is_cold));
+            weights.extend(
+                branches
+                    .iter()
+                    .map(|(_, _, branch)| weight(is_cold_branch(branch))),
+            );
+
+            switch
+                .set_metadata(context.metadata_node(&weights), context.get_kind_id("prof"))
+                .unwrap();
+        }
 
         for ((_, _, branch_expr), (_, block)) in branches.iter().zip(cases) {
             builder.position_at_end(block);
@@ -5820,21 +5919,25 @@ fn build_procedures_help<'a>(
 
                 fn_val.print_to_stderr();
 
+                let mono_ir = proc.to_pretty(layout_interner, 200, true);
+
                 if let Some(app_ll_file) = debug_output_file {
                     env.module.print_to_file(app_ll_file).unwrap();
 
-                    panic!(
-                        r"😱 LLVM errors when defining function {:?}; I wrote the full LLVM IR to {:?}",
+                    internal_error!(
+                        "😱 LLVM errors when defining function {:?}; I wrote the full LLVM IR to {:?}. It was generated from this mono IR:\n\n{}",
                         fn_val.get_name().to_str().unwrap(),
                         app_ll_file,
+                        mono_ir,
                     );
                 } else {
                     env.module.print_to_stderr();
 
-                    panic!(
-                    "The preceding code was from {:?}, which failed LLVM verification in {} build.",
-                     fn_val.get_name().to_str().unwrap(),
-                    mode,
+                    internal_error!(
+                        "The preceding code was from {:?}, which failed LLVM verification in {} build. It was generated from this mono IR:\n\n{}",
+                        fn_val.get_name().to_str().unwrap(),
+                        mode,
+                        mono_ir,
                     )
                 }
             }
@@ -5942,6 +6045,20 @@ fn build_proc_header<'a, 'ctx>(
 
     debug_info_init!(env, fn_val);
 
+    // Probe every page of a large stack frame, so deep recursion hits the guard page
+    // instead of silently jumping over it into unrelated memory.
+    if env.probe_stack
+        && matches!(
+            env.target.architecture(),
+            roc_target::Architecture::X86_64 | roc_target::Architecture::X86_32
+        )
+    {
+        let probe_stack = env
+            .context
+            .create_string_attribute("probe-stack", "inline-asm");
+        fn_val.add_attribute(AttributeLoc::Function, probe_stack);
+    }
+
     if env.exposed_to_host.contains(&symbol) {
         let arguments = Vec::from_iter_in(proc.args.iter().map(|(layout, _)| *layout), env.arena);
         expose_function_to_host(
@@ -6246,6 +6363,10 @@ fn build_proc<'a, 'ctx>(
 
     debug_info_init!(env, fn_val);
 
+    if let Some(stack_limit) = env.stack_limit {
+        build_stack_limit_check(env, fn_val, stack_limit);
+    }
+
     // Add args to scope
     for (arg_val, (layout, arg_symbol)) in fn_val.get_param_iter().zip(args) {
         arg_val.set_name(arg_symbol.as_str(&env.interns));
@@ -6270,6 +6391,73 @@ fn build_proc<'a, 'ctx>(
     }
 }
 
+/// Pointer to the lowest stack address roc procs may use. Zero until the first roc proc runs.
+fn get_stack_limit_ptr<'ctx>(env: &Env<'_, 'ctx, '_>) -> PointerValue<'ctx> {
+    let ptr_int = env.ptr_int();
+
+    let global_name = "roc_stack_limit";
+    let global = env.module.get_global(global_name).unwrap_or_else(|| {
+        let global = env.module.add_global(ptr_int, None, global_name);
+        global.set_initializer(&ptr_int.const_zero());
+        global
+    });
+
+    global.as_pointer_value()
+}
+
+/// Panic with "stack overflow" when the stack has grown more than `stack_limit` bytes
+/// past where it was when the first roc proc ran.
+fn build_stack_limit_check<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    parent: FunctionValue<'ctx>,
+    stack_limit: u32,
+) {
+    let builder = env.builder;
+    let context = env.context;
+    let ptr_int = env.ptr_int();
+
+    let stack_pointer = env.call_intrinsic(LLVM_STACK_SAVE, &[]);
+    let stack_pointer =
+        builder.new_build_ptr_to_int(stack_pointer.into_pointer_value(), ptr_int, "stack_pointer");
+
+    let limit_ptr = get_stack_limit_ptr(env);
+    let limit = builder
+        .new_build_load(ptr_int, limit_ptr, "stack_limit")
+        .into_int_value();
+
+    // The stack grows down, so the first roc proc to run decides the lowest allowed address.
+    // Clamp it at 1, because 0 means no limit has been recorded yet.
+    let size = ptr_int.const_int(stack_limit as u64, false);
+    let fits = builder.new_build_int_compare(IntPredicate::UGT, stack_pointer, size, "fits");
+    let lowest = builder.new_build_int_sub(stack_pointer, size, "lowest");
+    let lowest = builder
+        .new_build_select(fits, lowest, ptr_int.const_int(1, false), "lowest")
+        .into_int_value();
+
+    let is_unset =
+        builder.new_build_int_compare(IntPredicate::EQ, limit, ptr_int.const_zero(), "is_unset");
+    let limit = builder
+        .new_build_select(is_unset, lowest, limit, "stack_limit")
+        .into_int_value();
+    builder.new_build_store(limit_ptr, limit);
+
+    let has_room =
+        builder.new_build_int_compare(IntPredicate::UGE, stack_pointer, limit, "has_room");
+
+    // running out of stack is the cold path
+    let has_room = expect_bool(env, has_room, true);
+
+    let continue_block = context.append_basic_block(parent, "stack_ok");
+    let overflow_block = context.append_basic_block(parent, "stack_overflow");
+
+    builder.new_build_conditional_branch(has_room, continue_block, overflow_block);
+
+    builder.position_at_end(overflow_block);
+    throw_internal_exception(env, parent, "stack overflow");
+
+    builder.position_at_end(continue_block);
+}
+
 pub fn verify_fn(fn_val: FunctionValue<'_>) {
     if !fn_val.verify(print_fn_verification_output()) {
         unsafe {
@@ -7000,7 +7188,7 @@ fn define_global_str_literal<'ctx>(
     let module = env.module;
 
     // hash the name so we don't re-define existing messages
-    let name = {
+    let base_name = {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
 
@@ -7011,40 +7199,57 @@ fn define_global_str_literal<'ctx>(
         format!("_str_literal_{hash}")
     };
 
-    match module.get_global(&name) {
-        Some(current) => current,
-
-        None => {
-            let size = message.bytes().len() + env.target.ptr_width() as usize;
-            let mut bytes = Vec::with_capacity_in(size, env.arena);
+    let build_initializer = || {
+        let size = message.bytes().len() + env.target.ptr_width() as usize;
+        let mut bytes = Vec::with_capacity_in(size, env.arena);
 
-            // insert NULL bytes for the refcount
-            for _ in 0..env.target.ptr_width() as usize {
-                bytes.push(env.context.i8_type().const_zero());
-            }
+        // insert NULL bytes for the refcount
+        for _ in 0..env.target.ptr_width() as usize {
+            bytes.push(env.context.i8_type().const_zero());
+        }
 
-            // then add the data bytes
-            for b in message.bytes() {
-                bytes.push(env.context.i8_type().const_int(b as u64, false));
-            }
+        // then add the data bytes
+        for b in message.bytes() {
+            bytes.push(env.context.i8_type().const_int(b as u64, false));
+        }
 
-            // use None for the address space (e.g. Const does not work)
-            let typ = env.context.i8_type().array_type(bytes.len() as u32);
-            let global = module.add_global(typ, None, &name);
+        env.context.i8_type().const_array(bytes.into_bump_slice())
+    };
 
-            global.set_initializer(&env.context.i8_type().const_array(bytes.into_bump_slice()));
+    // LLVM constants are uniqued, so an existing global with an equal initializer holds
+    // exactly these bytes. Anything else under this name is a hash collision. The
+    // initializer is only built when there is an existing global to compare against.
+    let mut initializer = None;
+    let mut name = base_name.clone();
+    let mut collisions = 0;
 
-            // mimic the `global_string` function; we cannot use it directly because it assumes
-            // strings are NULL-terminated, which means we can't store the refcount (which is 8
-            // NULL bytes)
-            global.set_constant(true);
-            global.set_alignment(env.target.ptr_width() as u32);
-            global.set_unnamed_addr(true);
-            global.set_linkage(inkwell::module::Linkage::Private);
+    while let Some(current) = module.get_global(&name) {
+        let initializer = *initializer.get_or_insert_with(build_initializer);
 
-            global
+        if current.get_initializer() == Some(initializer.into()) {
+            return current;
         }
+
+        collisions += 1;
+        name = format!("{base_name}_{collisions}");
     }
+
+    let initializer = initializer.unwrap_or_else(build_initializer);
+
+    // use None for the address space (e.g. Const does not work)
+    let global = module.add_global(initializer.get_type(), None, &name);
+
+    global.set_initializer(&initializer);
+
+    // mimic the `global_string` function; we cannot use it directly because it assumes
+    // strings are NULL-terminated, which means we can't store the refcount (which is 8
+    // NULL bytes)
+    global.set_constant(true);
+    global.set_alignment(env.target.ptr_width() as u32);
+    global.set_unnamed_addr(true);
+    global.set_linkage(inkwell::module::Linkage::Private);
+
+    global
 }
 
 pub(crate) fn throw_internal_exception<'ctx>(
@@ -98,6 +98,7 @@ pub fn load<'ctx>(
         ErasedField::Value => 0,
         ErasedField::ValuePtr => 0,
         ErasedField::Callee => 1,
+        ErasedField::Refcounter => 3,
     };
 
     let value = env
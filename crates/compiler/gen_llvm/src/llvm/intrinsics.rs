@@ -108,6 +108,13 @@ pub(crate) fn add_intrinsics<'ctx>(ctx: &'ctx Context, module: &Module<'ctx>) {
         i8_ptr_type.fn_type(&[], false),
     );
 
+    add_intrinsic(
+        ctx,
+        module,
+        LLVM_EXPECT_I1,
+        i1_type.fn_type(&[i1_type.into(), i1_type.into()], false),
+    );
+
     add_int_intrinsic(ctx, module, &LLVM_ADD_WITH_OVERFLOW, |t| {
         let fields = [t.into(), i1_type.into()];
         ctx.struct_type(&fields, false)
@@ -140,6 +147,7 @@ pub static LLVM_MEMSET_I32: &str = "llvm.memset.p0i8.i32";
 
 pub static LLVM_FRAME_ADDRESS: &str = "llvm.frameaddress.p0";
 pub static LLVM_STACK_SAVE: &str = "llvm.stacksave";
+pub static LLVM_EXPECT_I1: &str = "llvm.expect.i1";
 
 pub static LLVM_SETJMP: &str = "llvm.eh.sjlj.setjmp";
 pub static LLVM_LONGJMP: &str = "llvm.eh.sjlj.longjmp";
@@ -59,7 +59,7 @@ use crate::llvm::{
 
 use super::{build::Env, convert::zig_dec_type};
 use super::{
-    build::{throw_internal_exception, use_roc_value, FAST_CALL_CONV},
+    build::{expect_bool, throw_internal_exception, use_roc_value, FAST_CALL_CONV},
     convert::zig_with_overflow_roc_dec,
     scope::Scope,
 };
@@ -1865,6 +1865,9 @@ fn throw_on_overflow<'ctx>(
         "has_not_overflowed",
     );
 
+    // overflow is the cold path; let LLVM lay out the happy path as the fall-through
+    let condition = expect_bool(env, condition, true);
+
     let then_block = context.append_basic_block(parent, "then_block");
     let throw_block = context.append_basic_block(parent, "throw_block");
 
@@ -2558,6 +2561,9 @@ fn int_neg_raise_on_overflow<'ctx>(
     let min_val = int_type_signed_min(int_type);
     let condition = builder.new_build_int_compare(IntPredicate::EQ, arg, min_val, "is_min_val");
 
+    // the minimum value is the cold path
+    let condition = expect_bool(env, condition, false);
+
     let block = env.builder.get_insert_block().expect("to be in a function");
     let parent = block.get_parent().expect("to be in a function");
     let then_block = env.context.append_basic_block(parent, "then");
@@ -2589,6 +2595,9 @@ fn int_abs_raise_on_overflow<'ctx>(
     let min_val = int_type_signed_min(int_type);
     let condition = builder.new_build_int_compare(IntPredicate::EQ, arg, min_val, "is_min_val");
 
+    // the minimum value is the cold path
+    let condition = expect_bool(env, condition, false);
+
     let block = env.builder.get_insert_block().expect("to be in a function");
     let parent = block.get_parent().expect("to be in a function");
     let then_block = env.context.append_basic_block(parent, "then");
@@ -3,6 +3,7 @@ use inkwell::{
     values::{BasicValueEnum, FunctionValue, PhiValue},
 };
 use roc_collections::ImMap;
+use roc_error_macros::internal_error;
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_mono::{
     ir::{JoinPointId, Param, ProcLayout},
@@ -28,14 +29,14 @@ impl<'a, 'ctx> Scope<'a, 'ctx> {
         match self.symbols.get(symbol) {
             Some((_, ptr)) => *ptr,
 
-            None => panic!("There was no entry for {symbol:?} {symbol} in scope {self:?}"),
+            None => internal_error!("There was no entry for {symbol:?} {symbol} in scope {self:?}"),
         }
     }
 
     pub fn load_symbol_and_layout(&self, symbol: &Symbol) -> (BasicValueEnum<'ctx>, InLayout<'a>) {
         match self.symbols.get(symbol) {
             Some((layout, ptr)) => (*ptr, *layout),
-            None => panic!("There was no entry for {symbol:?} in scope {self:?}"),
+            None => internal_error!("There was no entry for {symbol:?} in scope {self:?}"),
         }
     }
 
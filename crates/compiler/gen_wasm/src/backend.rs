@@ -839,18 +839,22 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         branches: &'a [(u64, BranchInfo<'a>, Stmt<'a>)],
         default_branch: &(BranchInfo<'a>, &'a Stmt<'a>),
     ) {
-        // NOTE currently implemented as a series of conditional jumps
-        // We may be able to improve this in the future with `Select`
-        // or `BrTable`
+        // Dense integer matches become a single `br_table`.
+        // Everything else is implemented as a series of conditional jumps.
+
+        let is_bool = matches!(cond_layout, Layout::BOOL);
+        let cond_type = WasmLayout::new(self.layout_interner, cond_layout).arg_types()[0];
+
+        if !is_bool && cond_type == ValueType::I32 && Self::is_dense_switch(branches) {
+            self.stmt_switch_br_table(cond_symbol, branches, default_branch);
+            return;
+        }
 
         // create a block for each branch except the default
         for _ in 0..branches.len() {
             self.start_block()
         }
 
-        let is_bool = matches!(cond_layout, Layout::BOOL);
-        let cond_type = WasmLayout::new(self.layout_interner, cond_layout).arg_types()[0];
-
         // then, we jump whenever the value under scrutiny is equal to the value of a branch
         for (i, (value, _, _)) in branches.iter().enumerate() {
             // put the cond_symbol on the top of the stack
@@ -900,6 +904,70 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         }
     }
 
+    /// A switch is worth a jump table if it has a few branches and
+    /// its values cover at least half of the range between the smallest and largest.
+    fn is_dense_switch(branches: &[(u64, BranchInfo<'a>, Stmt<'a>)]) -> bool {
+        const MIN_BRANCHES: usize = 4;
+
+        if branches.len() < MIN_BRANCHES {
+            return false;
+        }
+
+        let values = branches.iter().map(|(value, _, _)| *value as i32 as i64);
+        let min = values.clone().min().unwrap();
+        let max = values.max().unwrap();
+
+        (max - min + 1) as usize <= 2 * branches.len()
+    }
+
+    fn stmt_switch_br_table(
+        &mut self,
+        cond_symbol: Symbol,
+        branches: &'a [(u64, BranchInfo<'a>, Stmt<'a>)],
+        default_branch: &(BranchInfo<'a>, &'a Stmt<'a>),
+    ) {
+        let min = branches
+            .iter()
+            .map(|(value, _, _)| *value as i32)
+            .min()
+            .unwrap();
+        let max = branches
+            .iter()
+            .map(|(value, _, _)| *value as i32)
+            .max()
+            .unwrap();
+
+        // One block per branch, plus an innermost one for the default.
+        // Breaking out of block `i + 1` lands at the start of branch `i`,
+        // and breaking out of block 0 lands at the start of the default.
+        for _ in 0..=branches.len() {
+            self.start_block()
+        }
+
+        let mut targets =
+            bumpalo::vec![in self.env.arena; 0; (max as i64 - min as i64 + 1) as usize];
+        for (i, (value, _, _)) in branches.iter().enumerate() {
+            targets[(*value as i32 as i64 - min as i64) as usize] = i as u32 + 1;
+        }
+
+        // Out-of-range values wrap to a large unsigned index, which also goes to the default
+        self.storage
+            .load_symbols(&mut self.code_builder, &[cond_symbol]);
+        if min != 0 {
+            self.code_builder.i32_const(min);
+            self.code_builder.i32_sub();
+        }
+        self.code_builder.br_table(&targets, 0);
+
+        self.end_block();
+        self.stmt(default_branch.1);
+
+        for (_, _, branch) in branches.iter() {
+            self.end_block();
+            self.stmt(branch);
+        }
+    }
+
     fn stmt_join(
         &mut self,
         id: JoinPointId,
@@ -1126,10 +1194,6 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
                 initializer,
                 element_layout,
             } => self.expr_alloca(*initializer, *element_layout, storage),
-
-            Expr::RuntimeErrorFunction(_) => {
-                todo!("Expression `{}`", expr.to_pretty(100, false))
-            }
         }
     }
 
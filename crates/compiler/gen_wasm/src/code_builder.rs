@@ -334,9 +334,14 @@ impl<'a> CodeBuilder<'a> {
     pub fn br_if(&mut self, levels: u32) {
         self.inst_imm32(BRIF, levels);
     }
-    #[allow(dead_code)]
-    fn br_table() {
-        unimplemented!("br_table instruction is not currently used");
+    pub fn br_table(&mut self, targets: &[u32], default: u32) {
+        self.inst_base(BRTABLE);
+        self.code.encode_u32(targets.len() as u32);
+        for target in targets.iter() {
+            self.code.encode_u32(*target);
+        }
+        self.code.encode_u32(default);
+        log_instruction!("{:10}\t{:?} {}", format!("{BRTABLE:?}"), targets, default);
     }
 
     instruction_no_args!(return_, RETURN);
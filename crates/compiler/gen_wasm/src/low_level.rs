@@ -1621,7 +1621,10 @@ impl<'a> LowLevelCall<'a> {
                 let width = match ret_type {
                     CodeGenNumType::I32 => IntWidth::I32,
                     CodeGenNumType::I64 => IntWidth::I64,
-                    CodeGenNumType::I128 => todo!("{:?} for I128", self.lowlevel),
+                    CodeGenNumType::I128 => match self.ret_layout_raw {
+                        LayoutRepr::Builtin(Builtin::Int(width)) => width,
+                        _ => panic_ret_type(),
+                    },
                     _ => internal_error!("Invalid return type for round: {:?}", ret_type),
                 };
 
@@ -1640,11 +1643,31 @@ impl<'a> LowLevelCall<'a> {
                 let width = match ret_type {
                     CodeGenNumType::I32 => IntWidth::I32,
                     CodeGenNumType::I64 => IntWidth::I64,
-                    CodeGenNumType::I128 => todo!("{:?} for I128", self.lowlevel),
+                    CodeGenNumType::I128 => match self.ret_layout_raw {
+                        LayoutRepr::Builtin(Builtin::Int(width)) => width,
+                        _ => panic_ret_type(),
+                    },
                     _ => internal_error!("Invalid return type for round: {:?}", ret_type),
                 };
 
                 match (arg_type, self.lowlevel) {
+                    // Wasm has no instruction to truncate a float to a 128-bit integer
+                    (F32, NumCeiling) if ret_type == I128 => {
+                        return backend
+                            .call_host_fn_after_loading_args(&bitcode::NUM_CEILING_F32[width]);
+                    }
+                    (F64, NumCeiling) if ret_type == I128 => {
+                        return backend
+                            .call_host_fn_after_loading_args(&bitcode::NUM_CEILING_F64[width]);
+                    }
+                    (F32, NumFloor) if ret_type == I128 => {
+                        return backend
+                            .call_host_fn_after_loading_args(&bitcode::NUM_FLOOR_F32[width]);
+                    }
+                    (F64, NumFloor) if ret_type == I128 => {
+                        return backend
+                            .call_host_fn_after_loading_args(&bitcode::NUM_FLOOR_F64[width]);
+                    }
                     (F32, NumCeiling) => {
                         backend.code_builder.f32_ceil();
                     }
@@ -1672,7 +1695,6 @@ impl<'a> LowLevelCall<'a> {
                     (I32, F64) => backend.code_builder.i32_trunc_s_f64(),
                     (I64, F32) => backend.code_builder.i64_trunc_s_f32(),
                     (I64, F64) => backend.code_builder.i64_trunc_s_f64(),
-                    (I128, _) => todo!("{:?} for I128", self.lowlevel),
                     _ => panic_ret_type(),
                 }
             }
@@ -1688,7 +1710,10 @@ impl<'a> LowLevelCall<'a> {
                 let width = match ret_type {
                     CodeGenNumType::I32 => IntWidth::I32,
                     CodeGenNumType::I64 => IntWidth::I64,
-                    CodeGenNumType::I128 => todo!("{:?} for I128", self.lowlevel),
+                    CodeGenNumType::I128 => match self.ret_layout_raw {
+                        LayoutRepr::Builtin(Builtin::Int(width)) => width,
+                        _ => panic_ret_type(),
+                    },
                     _ => internal_error!("Invalid return type for pow: {:?}", ret_type),
                 };
 
@@ -2655,28 +2655,112 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
 
-    This expression is used in an unexpected way:
+    This pattern does not cover all the possibilities:
 
     8│      (Left y) = x
-                       ^
+             ^^^^^^
 
-    This `x` value is a:
+    Other possibilities include:
 
-        [Right Str, …]
+        Right _
 
-    But you are trying to use it as:
+    I would have to crash if I saw one of those! You can use a binding to
+    deconstruct a value if there is only ONE possibility. Use a `when` to
+    account for all possibilities.
+    "
+    );
 
-        […]
+    test_report!(
+        patterns_let_list_not_exhaustive,
+        indoc!(
+            r#"
+            x : List Str
+            x = ["a"]
 
-    Tip: Looks like a closed tag union does not have the `Right` tag.
+            [y] = x
 
-    Tip: Closed tag unions can't grow, because that might change the size
-    in memory. Can you use an open tag union?
+            y
+            "#
+        ),
+        @r#"
+    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+
+    This pattern does not cover all the possibilities:
+
+    7│      [y] = x
+            ^^^
+
+    Other possibilities include:
+
+        []
+        [_, _, ..]
+
+    I would have to crash if I saw one of those! You can use a binding to
+    deconstruct a value if there is only ONE possibility. Use a `when` to
+    account for all possibilities.
+    "#
+    );
+
+    test_report!(
+        patterns_let_result_not_exhaustive,
+        indoc!(
+            r#"
+            f : {} -> Result Str Str
+            f = \_ -> Ok "a"
+
+            (Ok y) = f {}
+
+            y
+            "#
+        ),
+        @r"
+    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+
+    This pattern does not cover all the possibilities:
+
+    7│      (Ok y) = f {}
+             ^^^^
+
+    Other possibilities include:
+
+        Err _
+
+    I would have to crash if I saw one of those! You can use a binding to
+    deconstruct a value if there is only ONE possibility. Use a `when` to
+    account for all possibilities.
     "
     );
 
+    test_no_problem!(
+        patterns_let_record_exhaustive,
+        indoc!(
+            r"
+            r = { a: 1, b: 2 }
+
+            { a, b } = r
+
+            a + b
+            "
+        )
+    );
+
+    test_no_problem!(
+        patterns_let_opaque_exhaustive,
+        indoc!(
+            r"
+            Age := U8
+
+            o = @Age 21
+
+            (@Age x) = o
+
+            x
+            "
+        )
+    );
+
     test_report!(
         patterns_when_not_exhaustive,
         indoc!(
@@ -2,18 +2,18 @@
 
 use bumpalo::collections::vec::Vec;
 use bumpalo::collections::CollectIn;
-use roc_error_macros::todo_lambda_erasure;
 use roc_module::low_level::{LowLevel, LowLevel::*};
 use roc_module::symbol::{IdentIds, Symbol};
 use roc_target::PtrWidth;
 
 use crate::code_gen_help::let_lowlevel;
 use crate::ir::{
-    BranchInfo, Call, CallType, Expr, JoinPointId, Literal, ModifyRc, Param, Stmt, UpdateModeId,
+    BranchInfo, Call, CallType, ErasedField, Expr, JoinPointId, Literal, ModifyRc, Param, Stmt,
+    UpdateModeId,
 };
 use crate::layout::{
-    Builtin, InLayout, Layout, LayoutInterner, LayoutRepr, STLayoutInterner, TagIdIntType,
-    UnionLayout,
+    Builtin, FunctionPointer, InLayout, Layout, LayoutInterner, LayoutRepr, STLayoutInterner,
+    TagIdIntType, UnionLayout,
 };
 
 use super::{CodeGenHelp, Context, HelperOp};
@@ -231,9 +231,7 @@ pub fn refcount_generic<'a>(
                 structure,
             )
         }
-        LayoutRepr::Erased(_) => {
-            todo_lambda_erasure!()
-        }
+        LayoutRepr::Erased(_) => refcount_erased(root, ident_ids, ctx, layout_interner, structure),
         LayoutRepr::RecursivePointer(_) => unreachable!(
             "We should never call a refcounting helper on a RecursivePointer layout directly"
         ),
@@ -727,6 +725,116 @@ fn modify_refcount<'a>(
     }
 }
 
+/// Generate a procedure to modify the reference count of a type-erased value
+///
+/// The value is a boxed set of captures, or null. Incrementing it only touches the refcount,
+/// but decrementing it may have to drop the captures, so that goes through the refcounter
+/// stored next to the value.
+fn refcount_erased<'a>(
+    root: &CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    structure: Symbol,
+) -> Stmt<'a> {
+    let arena = root.arena;
+
+    // value = ErasedLoad(structure, .ValuePtr)
+    let value = root.create_symbol(ident_ids, "value");
+    let value_expr = Expr::ErasedLoad {
+        symbol: structure,
+        field: ErasedField::ValuePtr,
+    };
+    let value_stmt = |next| Stmt::Let(value, value_expr, Layout::OPAQUE_PTR, next);
+
+    // is_null = value == null
+    let null = root.create_symbol(ident_ids, "null");
+    let null_stmt = |next| Stmt::Let(null, Expr::NullPointer, Layout::OPAQUE_PTR, next);
+
+    let is_null = root.create_symbol(ident_ids, "is_null");
+    let is_null_stmt = |next| let_lowlevel(arena, LAYOUT_BOOL, is_null, Eq, &[value, null], next);
+
+    let return_unit = arena.alloc(rc_return_stmt(root, ident_ids, ctx));
+
+    let modify_value = match ctx.op {
+        HelperOp::Inc => {
+            let alignment = root.target.ptr_width() as u32;
+            modify_refcount(
+                root,
+                ident_ids,
+                ctx,
+                Pointer::ToData(value),
+                alignment,
+                return_unit,
+            )
+        }
+        HelperOp::Dec => {
+            let refcounter_layout = layout_interner.insert_direct_no_semantic(
+                LayoutRepr::FunctionPointer(FunctionPointer {
+                    args: arena.alloc([Layout::OPAQUE_PTR]),
+                    ret: LAYOUT_UNIT,
+                }),
+            );
+
+            // refcounter = ErasedLoad(structure, .Refcounter)
+            let refcounter = root.create_symbol(ident_ids, "refcounter");
+            let refcounter_expr = Expr::ErasedLoad {
+                symbol: structure,
+                field: ErasedField::Refcounter,
+            };
+
+            // refcounter(value)
+            let unit = root.create_symbol(ident_ids, "unit");
+            let call_expr = Expr::Call(Call {
+                call_type: CallType::ByPointer {
+                    pointer: refcounter,
+                    ret_layout: LAYOUT_UNIT,
+                    arg_layouts: arena.alloc([Layout::OPAQUE_PTR]),
+                },
+                arguments: arena.alloc([value]),
+            });
+
+            Stmt::Let(
+                refcounter,
+                refcounter_expr,
+                refcounter_layout,
+                arena.alloc(
+                    //
+                    Stmt::Let(
+                        unit,
+                        call_expr,
+                        LAYOUT_UNIT,
+                        arena.alloc(
+                            //
+                            Stmt::Ret(unit),
+                        ),
+                    ),
+                ),
+            )
+        }
+        _ => unreachable!("erased values are only ever incremented or decremented"),
+    };
+
+    let if_stmt = Stmt::Switch {
+        cond_symbol: is_null,
+        cond_layout: LAYOUT_BOOL,
+        branches: arena.alloc([(1, BranchInfo::None, return_unit.clone())]),
+        default_branch: (BranchInfo::None, arena.alloc(modify_value)),
+        ret_layout: LAYOUT_UNIT,
+    };
+
+    value_stmt(arena.alloc(
+        //
+        null_stmt(arena.alloc(
+            //
+            is_null_stmt(arena.alloc(
+                //
+                if_stmt,
+            )),
+        )),
+    ))
+}
+
 /// Generate a procedure to modify the reference count of a Str
 fn refcount_str<'a>(
     root: &CodeGenHelp<'a>,
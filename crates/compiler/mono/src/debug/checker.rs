@@ -544,7 +544,6 @@ impl<'a, 'r> Ctx<'a, 'r> {
 
                 None
             }
-            Expr::RuntimeErrorFunction(_) => None,
         }
     }
 
@@ -851,7 +850,7 @@ impl<'a, 'r> Ctx<'a, 'r> {
                     });
                 }
             }
-            ErasedField::Callee => {
+            ErasedField::Callee | ErasedField::Refcounter => {
                 let repr = self.interner.get_repr(target_layout);
                 if !matches!(repr, LayoutRepr::FunctionPointer(_)) {
                     self.problem(ProblemKind::ErasedLoadCalleeNotFunctionPointer {
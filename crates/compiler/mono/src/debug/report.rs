@@ -510,6 +510,7 @@ fn format_use_kind(use_kind: UseKind) -> &'static str {
             ErasedField::Value => "erased value field",
             ErasedField::ValuePtr => "erased value pointer",
             ErasedField::Callee => "erased callee field",
+            ErasedField::Refcounter => "erased refcounter field",
         },
         UseKind::Erased => "erasure",
         UseKind::FunctionPointer => "function pointer",
@@ -238,14 +238,15 @@ fn specialize_drops_stmt<'a, 'i>(
                             ErasedField::Value => {
                                 environment.add_struct_child(*symbol, *binding, 0);
                             }
-                            ErasedField::Callee | ErasedField::ValuePtr => {
+                            ErasedField::Callee
+                            | ErasedField::ValuePtr
+                            | ErasedField::Refcounter => {
                                 // nothing to own
                             }
                         }
                     }
                     Reset { .. } | Expr::ResetRef { .. } => { /* do nothing */ }
-                    RuntimeErrorFunction(_)
-                    | FunctionPointer { .. }
+                    FunctionPointer { .. }
                     | GetTagId { .. }
                     | Alloca { .. }
                     | EmptyArray
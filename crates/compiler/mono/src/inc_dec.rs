@@ -907,12 +907,8 @@ fn insert_refcount_operations_binding<'a>(
     }
 
     match expr {
-        Expr::Literal(_)
-        | Expr::NullPointer
-        | Expr::FunctionPointer { .. }
-        | Expr::EmptyArray
-        | Expr::RuntimeErrorFunction(_) => {
-            // Literals, empty arrays, and runtime errors are not (and have nothing) reference counted.
+        Expr::Literal(_) | Expr::NullPointer | Expr::FunctionPointer { .. } | Expr::EmptyArray => {
+            // Literals and empty arrays are not (and have nothing) reference counted.
             new_let!(stmt)
         }
 
@@ -937,7 +933,7 @@ fn insert_refcount_operations_binding<'a>(
 
             match field {
                 ErasedField::Value => inc_owned!([*symbol], new_let),
-                ErasedField::Callee | ErasedField::ValuePtr => new_let,
+                ErasedField::Callee | ErasedField::ValuePtr | ErasedField::Refcounter => new_let,
             }
         }
 
@@ -1869,6 +1869,8 @@ pub enum ErasedField {
     /// Load a non-dereferenceable pointer to the value.
     ValuePtr,
     Callee,
+    /// Load the function that decrements the value, or null if there is no value.
+    Refcounter,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -1954,8 +1956,6 @@ pub enum Expr<'a> {
         symbol: Symbol,
         update_mode: UpdateModeId,
     },
-
-    RuntimeErrorFunction(&'a str),
 }
 
 impl<'a> Literal<'a> {
@@ -2118,8 +2118,6 @@ impl<'a> Expr<'a> {
             } => text!(alloc, "StructAtIndex {} ", index)
                 .append(symbol_to_doc(alloc, *structure, pretty)),
 
-            RuntimeErrorFunction(s) => text!(alloc, "ErrorFunction {}", s),
-
             GetTagId { structure, .. } => alloc
                 .text("GetTagId ")
                 .append(symbol_to_doc(alloc, *structure, pretty)),
@@ -2143,6 +2141,7 @@ impl<'a> Expr<'a> {
                     ErasedField::Value => ".Value",
                     ErasedField::ValuePtr => ".ValuePtr",
                     ErasedField::Callee => ".Callee",
+                    ErasedField::Refcounter => ".Refcounter",
                 };
 
                 alloc
@@ -2616,6 +2615,8 @@ fn from_can_let<'a>(
                             expr_var: def.expr_var,
                             pattern_vars: std::iter::once((anon_name, def.expr_var)).collect(),
                             annotation: None,
+                            pattern_var: def.expr_var,
+                            exhaustive: ExhaustiveMark::known_exhaustive(),
                         });
 
                         // f = #lam
@@ -2625,6 +2626,8 @@ fn from_can_let<'a>(
                             expr_var: def.expr_var,
                             pattern_vars: def.pattern_vars,
                             annotation: def.annotation,
+                            pattern_var: def.pattern_var,
+                            exhaustive: def.exhaustive,
                         });
 
                         let new_inner = LetNonRec(new_def, cont);
@@ -2644,6 +2647,8 @@ fn from_can_let<'a>(
                             pattern_vars: def.pattern_vars,
                             annotation: def.annotation,
                             expr_var: def.expr_var,
+                            pattern_var: def.pattern_var,
+                            exhaustive: def.exhaustive,
                         };
 
                         let new_inner = LetNonRec(Box::new(new_def), cont);
@@ -2745,6 +2750,31 @@ fn from_can_let<'a>(
         };
     }
 
+    if def.exhaustive.is_non_exhaustive(env.subs) {
+        // A destructure that does not cover every value, e.g. `Ok x = f y`, has been reported.
+        // Lower it as a `when` so that the uncovered values crash instead of being read as the
+        // one the pattern names.
+        let wrapped_cont = When {
+            cond_var: def.expr_var,
+            expr_var: variable,
+            region: def.loc_pattern.region,
+            loc_cond: Box::new(def.loc_expr),
+            branches: vec![roc_can::expr::WhenBranch {
+                patterns: vec![roc_can::expr::WhenBranchPattern {
+                    pattern: def.loc_pattern,
+                    degenerate: false,
+                }],
+                value: *cont,
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            }],
+            branches_cond_var: def.pattern_var,
+            exhaustive: def.exhaustive,
+        };
+
+        return lower_rest!(variable, wrapped_cont);
+    }
+
     // this may be a destructure pattern
     let (mono_pattern, assignments) =
         match from_can_pattern(env, procs, layout_cache, &def.loc_pattern.value) {
@@ -7803,7 +7833,7 @@ fn substitute_in_expr<'a>(
     use Expr::*;
 
     match expr {
-        Literal(_) | EmptyArray | RuntimeErrorFunction(_) => None,
+        Literal(_) | EmptyArray => None,
 
         Call(call) => substitute_in_call(arena, call, subs).map(Expr::Call),
 
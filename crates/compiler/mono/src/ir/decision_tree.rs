@@ -276,7 +276,12 @@ fn to_decision_tree<'a>(
                 .into_iter()
                 .map(|(test, branches)| {
                     if bs == branches {
-                        panic!();
+                        // an edge that leaves every branch in place would recurse forever
+                        internal_error!(
+                            "decision tree made no progress testing {:?} at path {:?}",
+                            test,
+                            path
+                        );
                     } else {
                         (test, to_decision_tree(interner, branches))
                     }
@@ -88,6 +88,8 @@ fn roc_function<'a>(
         ignore_problems: false,
         emit_debug_info: true,
         opt_level: OptLevel::Optimize,
+        probe_stack: false,
+        stack_limit: None,
     };
 
     let context = inkwell::context::Context::create();
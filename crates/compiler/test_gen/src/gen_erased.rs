@@ -1,11 +1,17 @@
 #[cfg(feature = "gen-llvm")]
 use crate::helpers::llvm::assert_evals_to_erased;
 
-#[cfg(feature = "gen-llvm")]
+#[cfg(feature = "gen-dev")]
+use crate::helpers::dev::assert_evals_to_erased;
+
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 use indoc::indoc;
 
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+use roc_std::RocStr;
+
 #[test]
-#[cfg(feature = "gen-llvm")]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn capture_multiple() {
     assert_evals_to_erased!(
         indoc!(
@@ -24,7 +30,7 @@ fn capture_multiple() {
 }
 
 #[test]
-#[cfg(feature = "gen-llvm")]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn multi_branch_capturing() {
     assert_evals_to_erased!(
         indoc!(
@@ -43,3 +49,46 @@ fn multi_branch_capturing() {
         (u64, u64)
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn call_erased_with_arguments() {
+    assert_evals_to_erased!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            f = \n ->
+              \a, b, c -> Str.concat (Num.toStr (a + b + c + n)) "!"
+
+            main = (f 1u64) 2 3 4
+            "#
+        ),
+        RocStr::from("10!"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn call_erased_capturing_heap_value_twice() {
+    assert_evals_to_erased!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            f = \s ->
+              \{} -> Str.concat s "!"
+
+            main =
+              g = f "a string that is too long to be a small string"
+
+              Str.concat (g {}) (g {})
+            "#
+        ),
+        RocStr::from(
+            "a string that is too long to be a small string!a string that is too long to be a small string!"
+        ),
+        RocStr
+    );
+}
@@ -3117,6 +3117,50 @@ fn lists_with_incompatible_type_param_in_if() {
     );
 }
 
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn list_destructure_def() {
+    // the destructure is reported as not exhaustive, but still runs when the value matches
+    assert_evals_to!(
+        indoc!(
+            r"
+            x : List I64
+            x = [42]
+
+            [y] = x
+
+            y
+            "
+        ),
+        42,
+        i64,
+        crate::helpers::llvm::identity,
+        true
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+#[should_panic(expected = r#"Roc failed with message: "NonExhaustivePattern""#)]
+fn list_destructure_def_not_matching() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            x : List I64
+            x = []
+
+            [y] = x
+
+            y
+            "
+        ),
+        42,
+        i64,
+        crate::helpers::llvm::identity,
+        true
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn map_with_index_multi_record() {
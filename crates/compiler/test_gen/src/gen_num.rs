@@ -11,7 +11,7 @@ use crate::helpers::wasm::assert_evals_to;
 #[allow(unused_imports)]
 use indoc::indoc;
 #[allow(unused_imports)]
-use roc_std::{RocDec, RocOrder, RocResult, I128, U128};
+use roc_std::{RocDec, RocList, RocOrder, RocResult, I128, U128};
 
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
@@ -1895,6 +1895,86 @@ fn pow_int() {
     assert_evals_to!("Num.powInt 2 3", 8, i64);
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn pow_int_i128() {
+    assert_evals_to!(
+        "Num.powInt 2i128 100",
+        I128::from(1267650600228229401496703205376i128),
+        I128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn round_f64_to_i128() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            n : I128
+            n = Num.round 100000000000000000000.0f64
+            n
+            "
+        ),
+        I128::from(100000000000000000000i128),
+        I128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn ceiling_to_i128() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            n : I128
+            n = Num.ceiling -1.5f64
+            n
+            "
+        ),
+        I128::from(-1),
+        I128
+    );
+    assert_evals_to!(
+        indoc!(
+            r"
+            n : I128
+            n = Num.ceiling 2.5f32
+            n
+            "
+        ),
+        I128::from(3),
+        I128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn floor_to_u128() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            n : U128
+            n = Num.floor 100000000000000000000.5f64
+            n
+            "
+        ),
+        U128::from(100000000000000000000u128),
+        U128
+    );
+    assert_evals_to!(
+        indoc!(
+            r"
+            n : U128
+            n = Num.floor 2.5f32
+            n
+            "
+        ),
+        U128::from(2),
+        U128
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn atan() {
@@ -2677,6 +2757,53 @@ fn when_on_i32() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn when_on_dense_u8() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                f : U8 -> U8
+                f = \x ->
+                    when x is
+                        3 -> 30
+                        4 -> 40
+                        5 -> 50
+                        7 -> 70
+                        _ -> 0
+
+                List.map [2, 3, 4, 5, 6, 7, 8, 255] f
+            "#
+        ),
+        RocList::from_slice(&[0, 30, 40, 50, 0, 70, 0, 0]),
+        RocList<u8>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn when_on_dense_i32_with_gaps() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                f : I32 -> I32
+                f = \x ->
+                    when x is
+                        -2 -> 1
+                        0 -> 2
+                        1 -> 3
+                        3 -> 4
+                        5 -> 5
+                        _ -> 0
+
+                List.map [-3, -2, -1, 0, 1, 2, 3, 4, 5, 6] f
+            "#
+        ),
+        RocList::from_slice(&[0, 1, 0, 2, 3, 0, 4, 0, 5, 0]),
+        RocList<i32>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn when_on_i16() {
@@ -3054,6 +3181,19 @@ fn sub_saturated() {
         127,
         i8
     );
+    assert_evals_to!(
+        indoc!(
+            r"
+            x : Dec
+            x = 1.5
+            y : Dec
+            y = 0.25
+            Num.subSaturated x y
+            "
+        ),
+        RocDec::from_str("1.25").unwrap(),
+        RocDec
+    );
 }
 
 #[test]
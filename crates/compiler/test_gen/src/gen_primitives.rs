@@ -3638,6 +3638,30 @@ fn mutual_recursion_top_level_defs() {
     )
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn mutual_recursion_local_defs() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            isEven = \n ->
+                when n is
+                    0 -> Bool.true
+                    _ -> isOdd (n - 1)
+
+            isOdd = \n ->
+                when n is
+                    0 -> Bool.false
+                    _ -> isEven (n - 1)
+
+            isEven 10
+            "
+        ),
+        true,
+        bool
+    )
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn polymorphic_lambda_captures_polymorphic_value() {
@@ -4664,3 +4688,154 @@ fn multiple_uses_of_bool_true_tag_union() {
         bool
     );
 }
+
+#[test]
+#[cfg(all(feature = "gen-llvm", target_arch = "x86_64"))]
+fn probe_stack_only_when_enabled() {
+    use crate::helpers::llvm::app_probe_stack_attributes;
+
+    let src = indoc!(
+        r"
+        fib = \n -> if n < 2 then n else fib (n - 1) + fib (n - 2)
+
+        fib 10
+        "
+    );
+
+    let disabled = app_probe_stack_attributes(src, false);
+    assert!(!disabled.is_empty());
+    assert!(disabled.iter().all(Option::is_none));
+
+    let enabled = app_probe_stack_attributes(src, true);
+    assert!(!enabled.is_empty());
+    assert!(enabled
+        .iter()
+        .all(|attr| attr.as_deref() == Some("inline-asm")));
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn crash_branches_are_unlikely() {
+    use crate::helpers::llvm::app_llvm_ir;
+
+    let src = indoc!(
+        r#"
+        pick = \n ->
+            when n is
+                0 -> 10
+                1 -> 20
+                _ -> crash "not a bit"
+
+        check = \b -> if b then 1 else crash "not true"
+
+        pick 1 + check Bool.true + Num.neg 3i64
+        "#
+    );
+
+    let ir = app_llvm_ir(src);
+
+    // the overflow checks, the `Num.neg` minimum value check and the `if` that crashes
+    assert!(ir.contains("call i1 @llvm.expect.i1"));
+    // the `when` that crashes
+    assert!(ir.contains("!prof"));
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn module_uses_cross_compilation_target() {
+    use inkwell::{context::Context, targets::RelocMode, OptimizationLevel};
+    use roc_build::target::{set_module_target, target_machine, target_triple_str};
+    use roc_gen_llvm::llvm::build::module_from_builtins;
+    use roc_target::Target;
+
+    // any target other than the host will do
+    let target = if Target::from(target_lexicon::Triple::host()) == Target::LinuxArm64 {
+        Target::LinuxX64
+    } else {
+        Target::LinuxArm64
+    };
+
+    let context = Context::create();
+    let module = module_from_builtins(target, &context, "app");
+
+    set_module_target(&module, target);
+
+    let target_machine = target_machine(target, OptimizationLevel::None, RelocMode::PIC).unwrap();
+    let target_data = target_machine.get_target_data();
+
+    assert_eq!(
+        module.get_triple().as_str().to_str().unwrap(),
+        target_triple_str(target)
+    );
+    assert_eq!(
+        module.get_data_layout().as_str(),
+        target_data.get_data_layout().as_str()
+    );
+    assert_eq!(
+        target_data.get_pointer_byte_size(None),
+        target.ptr_width() as u32
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn keep_frame_pointers_for_profilers() {
+    use crate::helpers::llvm::app_keep_frame_pointers;
+
+    let src = indoc!(
+        r"
+        fib = \n -> if n < 2 then n else fib (n - 1) + fib (n - 2)
+
+        fib 10
+        "
+    );
+
+    let kept = app_keep_frame_pointers(src);
+
+    assert!(!kept.app_frame_pointers.is_empty());
+    assert!(kept
+        .app_frame_pointers
+        .iter()
+        .all(|attr| attr.as_deref() == Some("all")));
+    assert_eq!(kept.missing_symbols, Vec::<String>::new());
+    assert!(kept.roc_panic_nomerge);
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn stack_limit_not_reached() {
+    use crate::helpers::llvm::{identity, llvm_evals_to_with_stack_limit};
+
+    llvm_evals_to_with_stack_limit::<u64, _, _>(
+        indoc!(
+            r"
+            build = \n -> if n == 0 then [] else List.append (build (n - 1)) n
+
+            List.len (build 10)
+            "
+        ),
+        10,
+        identity,
+        64 * 1024,
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+#[should_panic(expected = r#"Roc failed with message: "stack overflow""#)]
+fn stack_limit_reached() {
+    use crate::helpers::llvm::{identity, llvm_evals_to_with_stack_limit};
+
+    llvm_evals_to_with_stack_limit::<u64, _, _>(
+        indoc!(
+            r"
+            build = \n -> if n == 0 then [] else List.append (build (n - 1)) n
+
+            List.len (build 100000)
+            "
+        ),
+        100000,
+        identity,
+        64 * 1024,
+    );
+}
@@ -49,6 +49,31 @@ fn string_neq() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn large_str_literal() {
+    assert_evals_to!(
+        r#""This string is too long to be stored as a small string""#,
+        RocStr::from("This string is too long to be stored as a small string"),
+        RocStr
+    );
+
+    assert_evals_to!(
+        indoc!(
+            r#"
+            long = "This string is too long to be stored as a small string"
+
+            Str.concat long long
+            "#
+        ),
+        RocStr::from(
+            "This string is too long to be stored as a small string\
+             This string is too long to be stored as a small string"
+        ),
+        RocStr
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn str_split_empty_delimiter() {
@@ -152,6 +152,52 @@ fn basic_enum() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn when_on_bool_layout_lists_both_tags() {
+    // the leading guard forces a switch on the Bool-sized union that lists both tags explicitly
+    assert_evals_to!(
+        indoc!(
+            r"
+                Fruit : [Apple, Banana]
+
+                score : Fruit, I64 -> I64
+                score = \fruit, n ->
+                    when fruit is
+                        _ if n > 10 -> 0
+                        Apple -> 1
+                        Banana -> 2
+
+                score Apple 0 + 10 * score Banana 0 + 100 * score Apple 20
+                "
+        ),
+        21,
+        i64
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+#[should_panic(expected = r#"Roc failed with message: "NonExhaustivePattern""#)]
+fn tag_destructure_def_not_matching() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                f : {} -> Result I64 Str
+                f = \_ -> Err "nope"
+
+                (Ok y) = f {}
+
+                y
+                "#
+        ),
+        42,
+        i64,
+        crate::helpers::llvm::identity,
+        true
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn even_odd() {
@@ -2294,6 +2340,49 @@ fn recursive_tag_id_in_allocation_basic() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn nullable_wrapped_tag_id_in_allocation() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Value : [
+                Nil,
+                A Value,
+                B Value,
+                C Value,
+                D Value,
+                E Value,
+                F Value,
+                G Value,
+                H Value,
+                I Value,
+            ]
+
+            score : Value -> U64
+            score = \value ->
+                when value is
+                    Nil -> 0
+                    A rest -> 1 + score rest
+                    B rest -> 2 + score rest
+                    C rest -> 3 + score rest
+                    D rest -> 4 + score rest
+                    E rest -> 5 + score rest
+                    F rest -> 6 + score rest
+                    G rest -> 7 + score rest
+                    H rest -> 8 + score rest
+                    I rest -> 9 + score rest
+
+            main = score (A (B (C (D (E (F (G (H (I Nil)))))))))
+            "#
+        ),
+        45,
+        u64
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn recursive_tag_id_in_allocation_eq() {
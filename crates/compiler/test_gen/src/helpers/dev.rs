@@ -36,6 +36,7 @@ pub fn helper(
     src: &str,
     _leak: bool,
     lazy_literals: bool,
+    function_kind: FunctionKind,
 ) -> (String, Vec<roc_problem::can::Problem>, Library) {
     use std::path::PathBuf;
 
@@ -61,7 +62,7 @@ pub fn helper(
         palette: roc_reporting::report::DEFAULT_PALETTE,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
-        function_kind: FunctionKind::LambdaSet,
+        function_kind,
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -326,6 +327,7 @@ pub(crate) fn asm_evals_to<T, U, F>(
     transform: F,
     leak: bool,
     lazy_literals: bool,
+    function_kind: FunctionKind,
 ) where
     U: PartialEq + std::fmt::Debug,
     F: FnOnce(T) -> U,
@@ -334,7 +336,7 @@ pub(crate) fn asm_evals_to<T, U, F>(
 
     let arena = Bump::new();
     let (_main_fn_name, errors, lib) =
-        crate::helpers::dev::helper(&arena, src, leak, lazy_literals);
+        crate::helpers::dev::helper(&arena, src, leak, lazy_literals, function_kind);
 
     let result = crate::helpers::dev::run_test_main::<T>(&lib);
 
@@ -394,9 +396,26 @@ macro_rules! assert_evals_to {
             $transform,
             $leak,
             $lazy_literals,
+            roc_load::FunctionKind::LambdaSet,
         );
     };
 }
 
+#[allow(unused_macros)]
+macro_rules! assert_evals_to_erased {
+    ($src:expr, $expected:expr, $ty:ty) => {{
+        $crate::helpers::dev::asm_evals_to::<$ty, _, _>(
+            $src,
+            $expected,
+            $crate::helpers::dev::identity,
+            true,
+            false,
+            roc_load::FunctionKind::Erased,
+        );
+    }};
+}
+
 #[allow(unused_imports)]
 pub(crate) use assert_evals_to;
+#[allow(unused_imports)]
+pub(crate) use assert_evals_to_erased;
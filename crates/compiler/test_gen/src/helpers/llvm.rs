@@ -230,6 +230,8 @@ fn create_llvm_module<'a>(
         mode: config.mode,
         // important! we don't want any procedures to get the C calling convention
         exposed_to_host: MutSet::default(),
+        probe_stack: config.probe_stack,
+        stack_limit: config.stack_limit,
     };
 
     // Add roc_alloc, roc_realloc, and roc_dealloc, since the repl has no
@@ -320,6 +322,8 @@ pub struct HelperConfig {
     pub ignore_problems: bool,
     pub emit_debug_info: bool,
     pub opt_level: OptLevel,
+    pub probe_stack: bool,
+    pub stack_limit: Option<u32>,
 }
 
 #[allow(dead_code)]
@@ -349,6 +353,175 @@ pub fn helper<'a>(
     (main_fn_name, delayed_errors, lib)
 }
 
+/// The `probe-stack` attribute of every function generated for the app module
+#[allow(dead_code)]
+pub(crate) fn app_probe_stack_attributes(src: &str, probe_stack: bool) -> Vec<Option<String>> {
+    use inkwell::attributes::AttributeLoc;
+
+    let arena = bumpalo::Bump::new();
+    let context = inkwell::context::Context::create();
+    let target = target_lexicon::Triple::host().into();
+
+    let config = HelperConfig {
+        mode: LlvmBackendMode::GenTest,
+        ignore_problems: false,
+        emit_debug_info: false,
+        // no inlining, so the app functions survive
+        opt_level: OptLevel::Development,
+        probe_stack,
+        stack_limit: None,
+    };
+
+    let (_main_fn_name, _delayed_errors, module) = create_llvm_module(
+        &arena,
+        src,
+        config,
+        &context,
+        target,
+        FunctionKind::LambdaSet,
+    );
+
+    module
+        .get_functions()
+        .filter(|function| {
+            let name = function.get_name().to_str().unwrap();
+            name.starts_with(roc_module::ident::ModuleName::APP)
+        })
+        .map(|function| {
+            function
+                .get_string_attribute(AttributeLoc::Function, "probe-stack")
+                .map(|attr| attr.get_string_value().to_str().unwrap().to_string())
+        })
+        .collect()
+}
+
+/// The LLVM IR of every function generated for the app module, before any optimization
+#[allow(dead_code)]
+pub(crate) fn app_llvm_ir(src: &str) -> String {
+    use inkwell::values::AnyValue;
+
+    let arena = bumpalo::Bump::new();
+    let context = inkwell::context::Context::create();
+    let target = target_lexicon::Triple::host().into();
+
+    let config = HelperConfig {
+        mode: LlvmBackendMode::GenTest,
+        ignore_problems: false,
+        emit_debug_info: false,
+        opt_level: OptLevel::Development,
+        probe_stack: false,
+        stack_limit: None,
+    };
+
+    let (_main_fn_name, _delayed_errors, module) = create_llvm_module(
+        &arena,
+        src,
+        config,
+        &context,
+        target,
+        FunctionKind::LambdaSet,
+    );
+
+    module
+        .get_functions()
+        .filter(|function| {
+            let name = function.get_name().to_str().unwrap();
+            name.starts_with(roc_module::ident::ModuleName::APP)
+        })
+        .map(|function| function.print_to_string().to_string())
+        .collect()
+}
+
+/// What `keep_frame_pointers` did to a module: the `frame-pointer` attribute of every app
+/// function, the defined functions whose names are missing from the object file, and whether
+/// calls to `roc_panic` may be merged
+#[allow(dead_code)]
+pub(crate) struct KeptFramePointers {
+    pub app_frame_pointers: Vec<Option<String>>,
+    pub missing_symbols: Vec<String>,
+    pub roc_panic_nomerge: bool,
+}
+
+#[allow(dead_code)]
+pub(crate) fn app_keep_frame_pointers(src: &str) -> KeptFramePointers {
+    use inkwell::attributes::{Attribute, AttributeLoc};
+    use inkwell::targets::{FileType, RelocMode};
+    use inkwell::OptimizationLevel;
+
+    let arena = bumpalo::Bump::new();
+    let context = inkwell::context::Context::create();
+    let target = target_lexicon::Triple::host().into();
+
+    let config = HelperConfig {
+        mode: LlvmBackendMode::GenTest,
+        ignore_problems: false,
+        emit_debug_info: false,
+        // no inlining, so the app functions survive
+        opt_level: OptLevel::Development,
+        probe_stack: false,
+        stack_limit: None,
+    };
+
+    let (_main_fn_name, _delayed_errors, module) = create_llvm_module(
+        &arena,
+        src,
+        config,
+        &context,
+        target,
+        FunctionKind::LambdaSet,
+    );
+
+    roc_gen_llvm::llvm::build::keep_frame_pointers(module);
+    module.strip_debug_info();
+
+    let (mpm, _fpm) =
+        roc_gen_llvm::llvm::build::construct_optimization_passes(module, config.opt_level);
+    mpm.run_on(module);
+
+    let app_frame_pointers = module
+        .get_functions()
+        .filter(|function| {
+            let name = function.get_name().to_str().unwrap();
+            name.starts_with(roc_module::ident::ModuleName::APP)
+        })
+        .map(|function| {
+            function
+                .get_string_attribute(AttributeLoc::Function, "frame-pointer")
+                .map(|attr| attr.get_string_value().to_str().unwrap().to_string())
+        })
+        .collect();
+
+    let nomerge = Attribute::get_named_enum_kind_id("nomerge");
+    let roc_panic_nomerge = module
+        .get_function("roc_panic")
+        .and_then(|function| function.get_enum_attribute(AttributeLoc::Function, nomerge))
+        .is_some();
+
+    let target_machine =
+        roc_build::target::target_machine(target, OptimizationLevel::None, RelocMode::PIC).unwrap();
+    let object = target_machine
+        .write_to_memory_buffer(module, FileType::Object)
+        .unwrap();
+    let object = object.as_slice();
+
+    let missing_symbols = module
+        .get_functions()
+        .filter(|function| function.count_basic_blocks() > 0)
+        .map(|function| function.get_name().to_str().unwrap().to_string())
+        .filter(|name| {
+            !object
+                .windows(name.len())
+                .any(|bytes| bytes == name.as_bytes())
+        })
+        .collect();
+
+    KeptFramePointers {
+        app_frame_pointers,
+        missing_symbols,
+        roc_panic_nomerge,
+    }
+}
+
 #[allow(dead_code)]
 fn write_final_wasm() -> bool {
     #[allow(unused_imports)]
@@ -480,6 +653,8 @@ where
         emit_debug_info: false,
         ignore_problems,
         opt_level: OPT_LEVEL,
+        probe_stack: false,
+        stack_limit: None,
     };
 
     let wasm_bytes = compile_to_wasm_bytes(&arena, config, src, &context, function_kind);
@@ -547,19 +722,57 @@ pub(crate) fn llvm_evals_to<T, U, F>(
     U: PartialEq + std::fmt::Debug,
     F: FnOnce(T) -> U,
 {
-    use bumpalo::Bump;
-    use inkwell::context::Context;
+    let config = crate::helpers::llvm::HelperConfig {
+        mode: LlvmBackendMode::GenTest,
+        emit_debug_info: false,
+        ignore_problems,
+        opt_level: crate::helpers::llvm::OPT_LEVEL,
+        probe_stack: false,
+        stack_limit: None,
+    };
 
-    let arena = Bump::new();
-    let context = Context::create();
+    llvm_evals_to_with_config(src, expected, transform, config, function_kind)
+}
 
+// only used in tests
+#[allow(dead_code)]
+pub(crate) fn llvm_evals_to_with_stack_limit<T, U, F>(
+    src: &str,
+    expected: U,
+    transform: F,
+    stack_limit: u32,
+) where
+    U: PartialEq + std::fmt::Debug,
+    F: FnOnce(T) -> U,
+{
     let config = crate::helpers::llvm::HelperConfig {
         mode: LlvmBackendMode::GenTest,
         emit_debug_info: false,
-        ignore_problems,
+        ignore_problems: false,
         opt_level: crate::helpers::llvm::OPT_LEVEL,
+        probe_stack: false,
+        stack_limit: Some(stack_limit),
     };
 
+    llvm_evals_to_with_config(src, expected, transform, config, FunctionKind::LambdaSet)
+}
+
+fn llvm_evals_to_with_config<T, U, F>(
+    src: &str,
+    expected: U,
+    transform: F,
+    config: HelperConfig,
+    function_kind: FunctionKind,
+) where
+    U: PartialEq + std::fmt::Debug,
+    F: FnOnce(T) -> U,
+{
+    use bumpalo::Bump;
+    use inkwell::context::Context;
+
+    let arena = Bump::new();
+    let context = Context::create();
+
     let (main_fn_name, errors, lib) =
         crate::helpers::llvm::helper(&arena, config, src, &context, function_kind);
 
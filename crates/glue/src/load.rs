@@ -58,6 +58,10 @@ pub fn generate(
                 opt_level: OptLevel::Development,
                 emit_debug_info: false,
                 emit_llvm_ir: false,
+                emit_llvm_bc: false,
+                probe_stack: false,
+                stack_limit: None,
+                keep_frame_pointers: false,
                 fuzz: false,
             };
 
@@ -217,6 +217,8 @@ fn mono_module_to_dylib_llvm<'a>(
         mode: LlvmBackendMode::GenTest, // so roc_panic is generated
         // important! we don't want any procedures to get the C calling convention
         exposed_to_host: MutSet::default(),
+        probe_stack: false,
+        stack_limit: None,
     };
 
     // Add roc_alloc, roc_realloc, and roc_dealloc, since the repl has no